@@ -0,0 +1,68 @@
+use aoc_2022_rust as lib;
+use lib::{Part, Puzzle, Stats};
+use std::fmt;
+use std::time::Duration;
+
+/// Timing statistics of one day over repeated runs. Parts that were not run
+/// are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bench {
+    pub day: usize,
+    pub runs: usize,
+    pub load: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    pub total: Stats,
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}",
+        stats.min, stats.median, stats.mean
+    )
+}
+
+impl fmt::Display for Bench {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "day {:02} ({:} runs)", self.day, self.runs)?;
+        writeln!(f, "  load    {:}", format_stats(&self.load))?;
+        if let Some(stats) = &self.part1 {
+            writeln!(f, "  part 1  {:}", format_stats(stats))?;
+        }
+        if let Some(stats) = &self.part2 {
+            writeln!(f, "  part 2  {:}", format_stats(stats))?;
+        }
+        write!(f, "  total   {:}", format_stats(&self.total))
+    }
+}
+
+/// Solves `input` `runs` times, each time with a fresh puzzle from
+/// `new_puzzle`, and summarizes the time spent in every step.
+pub fn bench_day(
+    day: usize,
+    input: &str,
+    part: Option<Part>,
+    runs: usize,
+    new_puzzle: impl Fn() -> Box<dyn Puzzle>,
+) -> lib::Result<Bench> {
+    let mut load = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+    let mut total = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let solution = new_puzzle().solve(input, part)?;
+        load.push(solution.load_time);
+        part1.extend(solution.part1.as_ref().map(|(_, t)| *t));
+        part2.extend(solution.part2.as_ref().map(|(_, t)| *t));
+        total.push(solution.total_time());
+    }
+    let stats = |samples: &[Duration]| Stats::new(samples).expect("at least one run");
+    Ok(Bench {
+        day,
+        runs,
+        load: stats(&load),
+        part1: Stats::new(&part1),
+        part2: Stats::new(&part2),
+        total: stats(&total),
+    })
+}
@@ -0,0 +1,229 @@
+use aoc_2022_rust::output::Format;
+use aoc_2022_rust::Part;
+use std::collections::BTreeSet;
+use std::fmt;
+
+pub const USAGE: &str = "\
+usage: aoc_2022_rust [DAYS] [OPTIONS]
+       aoc_2022_rust fetch [DAYS] [-y YEAR] [--base-url URL]
+
+DAYS is a comma separated list of days and day ranges, e.g. 3-7,12.
+All implemented days of the year are run when it is omitted.
+
+fetch downloads the missing inputs/N.input files with the session cookie from
+$AOC_COOKIE or .cookie. Existing files are never downloaded again.
+
+options:
+    -p, --part <1|2>      run only the given part
+    -i, --input <FILE>    read the input from FILE (single day only)
+    -t, --test            read the example input inputs/N.test
+    -y, --year <YEAR>     puzzle year (default: 2022)
+    -f, --format <FMT>    output format: text (default), json, csv or markdown
+        --show            draw the solutions of days that can, e.g. day 12's route
+        --verify          check the answers against inputs/N.answers
+        --bench <N>       run every part N times and report timing statistics
+    -l, --list            list the implemented days of every year
+        --base-url <URL>  server to fetch from (default: https://adventofcode.com)
+    -h, --help            print this help";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Puzzle,
+    Test,
+    File(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub year: usize,
+    /// The selected days, or `None` for all implemented days.
+    pub days: Option<Vec<usize>>,
+    pub part: Option<Part>,
+    pub source: Source,
+    pub verify: bool,
+    pub bench: Option<usize>,
+    pub format: Format,
+    pub list: bool,
+    pub fetch: bool,
+    pub base_url: Option<String>,
+    pub show: bool,
+    pub help: bool,
+}
+
+impl Options {
+    /// The parts to solve: the one given with `--part`, or both.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+
+    pub fn input_path(&self, day: usize) -> String {
+        match &self.source {
+            Source::Puzzle => format!("inputs/{:}.input", day),
+            Source::Test => format!("inputs/{:}.test", day),
+            Source::File(path) => path.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    UnknownOption(String),
+    MissingValue(String),
+    InvalidValue(String, String),
+    InvalidDays(String),
+    UnsupportedYear(usize),
+    Conflict(&'static str),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::UnknownOption(opt) => write!(f, "unknown option {:}", opt),
+            CliError::MissingValue(opt) => write!(f, "option {:} needs a value", opt),
+            CliError::InvalidValue(opt, value) => {
+                write!(f, "invalid value {:?} for option {:}", value, opt)
+            }
+            CliError::InvalidDays(spec) => {
+                write!(f, "invalid day selection {:?} (days are 1-25)", spec)
+            }
+            CliError::UnsupportedYear(year) => write!(f, "no solutions for year {:}", year),
+            CliError::Conflict(msg) => write!(f, "{:}", msg),
+        }
+    }
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Options, CliError> {
+    let mut year = 2022;
+    let mut days = None;
+    let mut part = None;
+    let mut input = None;
+    let mut test = false;
+    let mut verify = false;
+    let mut bench = None;
+    let mut format = Format::Text;
+    let mut list = false;
+    let mut fetch = false;
+    let mut base_url = None;
+    let mut show = false;
+    let mut help = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => help = true,
+            "-l" | "--list" => list = true,
+            "-t" | "--test" => test = true,
+            "--verify" => verify = true,
+            "--show" => show = true,
+            "-p" | "--part" => {
+                part = match value(&arg, args.next())?.as_str() {
+                    "1" => Some(Part::One),
+                    "2" => Some(Part::Two),
+                    other => return Err(CliError::InvalidValue(arg, other.to_string())),
+                }
+            }
+            "-i" | "--input" => input = Some(value(&arg, args.next())?),
+            "--base-url" => base_url = Some(value(&arg, args.next())?),
+            "--bench" => {
+                let v = value(&arg, args.next())?;
+                bench = match v.parse::<usize>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(CliError::InvalidValue(arg, v)),
+                };
+            }
+            "-f" | "--format" => {
+                let v = value(&arg, args.next())?;
+                format = v.parse().map_err(|_| CliError::InvalidValue(arg, v))?;
+            }
+            "-y" | "--year" => {
+                let v = value(&arg, args.next())?;
+                year = v.parse().map_err(|_| CliError::InvalidValue(arg, v))?;
+            }
+            _ if arg.starts_with('-') => return Err(CliError::UnknownOption(arg)),
+            "fetch" if !fetch && days.is_none() => fetch = true,
+            _ if days.is_none() => days = Some(parse_days(&arg)?),
+            _ => return Err(CliError::Conflict("only one day selection is allowed")),
+        }
+    }
+
+    if verify && (input.is_some() || test) {
+        return Err(CliError::Conflict(
+            "--verify reads the inputs named in inputs/N.answers",
+        ));
+    }
+    if fetch && (verify || bench.is_some() || input.is_some() || test || format != Format::Text) {
+        return Err(CliError::Conflict(
+            "fetch takes only a day selection, --year and --base-url",
+        ));
+    }
+    if base_url.is_some() && !fetch {
+        return Err(CliError::Conflict("--base-url needs the fetch command"));
+    }
+    if verify && bench.is_some() {
+        return Err(CliError::Conflict(
+            "--verify and --bench exclude each other",
+        ));
+    }
+    if show && (verify || bench.is_some() || fetch || format != Format::Text) {
+        return Err(CliError::Conflict(
+            "--show applies only when solving with the text format",
+        ));
+    }
+    if format != Format::Text && (verify || bench.is_some()) {
+        return Err(CliError::Conflict(
+            "--format applies only when solving, not with --verify or --bench",
+        ));
+    }
+    let source = match (input, test) {
+        (Some(_), true) => return Err(CliError::Conflict("--input and --test exclude each other")),
+        (Some(_), false) if !matches!(&days, Some(days) if days.len() == 1) => {
+            return Err(CliError::Conflict("--input needs exactly one day"))
+        }
+        (Some(path), false) => Source::File(path),
+        (None, true) => Source::Test,
+        (None, false) => Source::Puzzle,
+    };
+
+    Ok(Options {
+        year,
+        days,
+        part,
+        source,
+        verify,
+        bench,
+        format,
+        list,
+        fetch,
+        base_url,
+        show,
+        help,
+    })
+}
+
+fn value(opt: &str, value: Option<String>) -> Result<String, CliError> {
+    value.ok_or_else(|| CliError::MissingValue(opt.to_string()))
+}
+
+/// Parses a selection like `3-7,12` into a sorted list of distinct days.
+pub fn parse_days(spec: &str) -> Result<Vec<usize>, CliError> {
+    let invalid = || CliError::InvalidDays(spec.to_string());
+    let day = |s: &str| match s.trim().parse::<usize>() {
+        Ok(d) if (1..=25).contains(&d) => Ok(d),
+        _ => Err(invalid()),
+    };
+    let mut days = BTreeSet::new();
+    for item in spec.split(',') {
+        if let Some((a, b)) = item.split_once('-') {
+            let (a, b) = (day(a)?, day(b)?);
+            if a > b {
+                return Err(invalid());
+            }
+            days.extend(a..=b);
+        } else {
+            days.insert(day(item)?);
+        }
+    }
+    Ok(days.into_iter().collect())
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A simulation that repeats itself: the state after step `start + length`
+/// has the same fingerprint as the state after step `start`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+    /// The metric after each step up to `start + length`, beginning with
+    /// the initial state.
+    values: Vec<i64>,
+}
+
+impl Cycle {
+    /// The metric after `n` steps, assuming every further cycle adds as
+    /// much as the first one did.
+    pub fn extrapolate(&self, n: usize) -> i64 {
+	if n < self.values.len() {
+	    return self.values[n];
+	}
+	let (cycles, rest) = ((n - self.start) / self.length, (n - self.start) % self.length);
+	let gain = self.values[self.start + self.length] - self.values[self.start];
+	self.values[self.start + rest] + cycles as i64 * gain
+    }
+}
+
+/// Advances `state` with `step` until the `fingerprint` of a state repeats,
+/// recording `metric` along the way. Gives up with `None` after `limit`
+/// steps.
+pub fn find_cycle<S, K>(mut state: S,
+			limit: usize,
+			mut step: impl FnMut(&mut S),
+			mut fingerprint: impl FnMut(&S) -> K,
+			mut metric: impl FnMut(&S) -> i64) -> Option<Cycle>
+where K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut values = Vec::new();
+    for n in 0..=limit {
+	values.push(metric(&state));
+	if let Some(start) = seen.insert(fingerprint(&state), n) {
+	    return Some(Cycle { start, length: n - start, values });
+	}
+	step(&mut state);
+    }
+    None
+}
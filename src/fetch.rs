@@ -0,0 +1,327 @@
+use std::fmt;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::Path;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const COOKIE_FILE: &str = ".cookie";
+const USER_AGENT: &str = "github.com/tgaref by tgaref@gmail.com";
+
+/// Time to wait between two requests to the server.
+pub const REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Performs HTTP GET requests. `cookie` is sent as the `Cookie` header.
+pub trait Backend {
+    fn get(&self, url: &str, cookie: &str) -> Result<Response, String>;
+}
+
+/// Runs `curl`, which handles https.
+pub struct Curl;
+
+impl Backend for Curl {
+    fn get(&self, url: &str, cookie: &str) -> Result<Response, String> {
+        let output = Command::new("curl")
+            .args(["--silent", "--show-error", "--write-out", "\n%{http_code}"])
+            .args(["--cookie", cookie, "--user-agent", USER_AGENT, url])
+            .output()
+            .map_err(|e| format!("cannot run curl: {:}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "curl failed: {:}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let text = String::from_utf8(output.stdout).map_err(|_| "response is not UTF-8")?;
+        let (body, status) = text.rsplit_once('\n').ok_or("curl printed no status")?;
+        let status = status
+            .parse()
+            .map_err(|_| format!("curl printed invalid status {:?}", status))?;
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Speaks plain HTTP/1.0 over a TCP connection. Enough for a local mock
+/// server, but not for the real site, which needs https.
+pub struct PlainHttp;
+
+impl Backend for PlainHttp {
+    fn get(&self, url: &str, cookie: &str) -> Result<Response, String> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| format!("not an http:// url: {:}", url))?;
+        let (host, path) = match rest.find('/') {
+            Some(i) => rest.split_at(i),
+            None => (rest, "/"),
+        };
+        let address = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{:}:80", host)
+        };
+        let io_error = |e: std::io::Error| format!("{:}: {:}", host, e);
+        let mut stream = TcpStream::connect(&address).map_err(io_error)?;
+        write!(
+            stream,
+            "GET {:} HTTP/1.0\r\nHost: {:}\r\nCookie: {:}\r\nUser-Agent: {:}\r\n\r\n",
+            path, host, cookie, USER_AGENT
+        )
+        .map_err(io_error)?;
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).map_err(io_error)?;
+        let text = String::from_utf8(raw).map_err(|_| "response is not UTF-8")?;
+        let (head, body) = text
+            .split_once("\r\n\r\n")
+            .ok_or("response has no header end")?;
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| format!("invalid status line {:?}", head.lines().next()))?;
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Picks the backend that can talk to `base_url`.
+pub fn backend_for(base_url: &str) -> Box<dyn Backend> {
+    if base_url.starts_with("http://") {
+        Box::new(PlainHttp)
+    } else {
+        Box::new(Curl)
+    }
+}
+
+/// Reads the session cookie from `$AOC_COOKIE`, or else from `path`. The file
+/// may hold the bare value, `AOC_COOKIE=...` or the fish `set AOC_COOKIE ...`
+/// line the old getinput script sourced.
+pub fn read_cookie(path: &str) -> Result<String, String> {
+    let value = match std::env::var("AOC_COOKIE") {
+        Ok(value) => value,
+        Err(_) => {
+            let text = std::fs::read_to_string(path)
+                .map_err(|e| format!("no $AOC_COOKIE and cannot read {:}: {:}", path, e))?;
+            let line = text
+                .lines()
+                .map(str::trim)
+                .find(|l| !l.is_empty() && !l.starts_with('#'))
+                .ok_or_else(|| format!("{:} is empty", path))?;
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let line = line
+                .strip_prefix("set ")
+                .map(|l| l.trim_start().trim_start_matches("-x ").trim_start())
+                .unwrap_or(line);
+            let line = line.strip_prefix("AOC_COOKIE").unwrap_or(line);
+            line.trim_start_matches([' ', '=']).to_string()
+        }
+    };
+    let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+    if value.is_empty() {
+        return Err("the session cookie is empty".to_string());
+    }
+    if value.contains('=') {
+        Ok(value.to_string())
+    } else {
+        Ok(format!("session={:}", value))
+    }
+}
+
+/// Keeps consecutive requests at least `interval` apart.
+pub struct RateLimiter {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration) -> Self {
+        RateLimiter {
+            interval,
+            last: None,
+        }
+    }
+
+    pub fn wait(&mut self) {
+        if let Some(last) = self.last {
+            if let Some(rest) = self.interval.checked_sub(last.elapsed()) {
+                thread::sleep(rest);
+            }
+        }
+        self.last = Some(Instant::now());
+    }
+}
+
+/// Rejects anything that does not look like a puzzle input: failed requests,
+/// empty bodies and HTML pages (the site answers with one when the session
+/// has expired).
+pub fn validate(response: &Response) -> Result<(), String> {
+    let first_line = response.body.lines().next().unwrap_or("").trim();
+    if response.status != 200 {
+        return Err(format!(
+            "server answered {:}: {:}",
+            response.status, first_line
+        ));
+    }
+    if response.body.trim().is_empty() {
+        return Err("server sent an empty input".to_string());
+    }
+    let start = response.body.trim_start().to_ascii_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") || start.contains("<body") {
+        return Err("server sent an HTML page instead of an input".to_string());
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Cached,
+    Fetched(usize),
+    Error(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Cached => write!(f, "cached"),
+            Status::Fetched(bytes) => write!(f, "fetched ({:} bytes)", bytes),
+            Status::Error(e) => write!(f, "ERROR {:}", e),
+        }
+    }
+}
+
+/// Everything needed to download inputs.
+pub struct Fetcher<'a> {
+    pub backend: &'a dyn Backend,
+    pub base_url: &'a str,
+    pub cookie: String,
+    pub limiter: RateLimiter,
+}
+
+impl Fetcher<'_> {
+    /// Downloads the input of `day` to `path`, unless that file exists.
+    pub fn fetch(&mut self, year: usize, day: usize, path: &str) -> Status {
+        if Path::new(path).exists() {
+            return Status::Cached;
+        }
+        match self.download(year, day, path) {
+            Ok(bytes) => Status::Fetched(bytes),
+            Err(e) => Status::Error(e),
+        }
+    }
+
+    fn download(&mut self, year: usize, day: usize, path: &str) -> Result<usize, String> {
+        let url = format!(
+            "{:}/{:}/day/{:}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+        self.limiter.wait();
+        let response = self.backend.get(&url, &self.cookie)?;
+        validate(&response)?;
+        // Write next to the target first so that an interrupted download
+        // never leaves a truncated input behind.
+        let partial = format!("{:}.part", path);
+        std::fs::write(&partial, &response.body)
+            .and_then(|_| std::fs::rename(&partial, path))
+            .map_err(|e| format!("cannot write {:}: {:}", path, e))?;
+        Ok(response.body.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::path::PathBuf;
+
+    /// Answers every request with `response` and remembers the urls asked for.
+    struct Stub {
+        response: Response,
+        urls: RefCell<Vec<String>>,
+    }
+
+    impl Stub {
+        fn new(status: u16, body: &str) -> Self {
+            Stub {
+                response: Response {
+                    status,
+                    body: body.to_string(),
+                },
+                urls: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl Backend for Stub {
+        fn get(&self, url: &str, _cookie: &str) -> Result<Response, String> {
+            self.urls.borrow_mut().push(url.to_string());
+            Ok(self.response.clone())
+        }
+    }
+
+    /// A path in a fresh temporary directory, removed before the test runs.
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{:}-{:}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join("1.input")
+    }
+
+    fn fetch(backend: &Stub, path: &Path) -> Status {
+        let mut fetcher = Fetcher {
+            backend,
+            base_url: "http://localhost/",
+            cookie: "session=abc".to_string(),
+            limiter: RateLimiter::new(Duration::ZERO),
+        };
+        fetcher.fetch(2022, 1, path.to_str().unwrap())
+    }
+
+    #[test]
+    fn fetches_then_reports_cached() {
+        let path = scratch("cached");
+        let backend = Stub::new(200, "1000\n2000\n");
+        assert_eq!(fetch(&backend, &path), Status::Fetched(10));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        assert_eq!(fetch(&backend, &path), Status::Cached);
+        assert_eq!(
+            *backend.urls.borrow(),
+            ["http://localhost/2022/day/1/input"]
+        );
+    }
+
+    #[test]
+    fn rejects_html() {
+        let path = scratch("html");
+        let backend = Stub::new(200, "<!DOCTYPE html>\n<html><body>Log in</body></html>\n");
+        assert_eq!(
+            fetch(&backend, &path),
+            Status::Error("server sent an HTML page instead of an input".to_string())
+        );
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn reports_failed_requests() {
+        let path = scratch("404");
+        let backend = Stub::new(404, "404 Not Found\n");
+        assert_eq!(
+            fetch(&backend, &path),
+            Status::Error("server answered 404: 404 Not Found".to_string())
+        );
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,254 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use num::Signed;
+use crate::grid::{Pos, DOWN, LEFT, RIGHT, UP};
+
+/// A point in the plane. On a grid `x` is the column and `y` the row, so
+/// `y` grows downwards.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point in space.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+// Component-wise arithmetic and scaling for a point type.
+macro_rules! point_ops {
+    ($point:ident { $($c:ident),+ }) => {
+	impl<T> $point<T> {
+	    pub const fn new($($c: T),+) -> Self {
+		$point { $($c),+ }
+	    }
+	}
+
+	impl<T: Copy + Signed + Ord> $point<T> {
+	    /// Sum of the distances along each axis.
+	    pub fn manhattan(&self, other: &Self) -> T {
+		T::zero() $(+ (self.$c - other.$c).abs())+
+	    }
+
+	    /// Largest distance along any axis.
+	    pub fn chebyshev(&self, other: &Self) -> T {
+		T::zero() $(.max((self.$c - other.$c).abs()))+
+	    }
+
+	    /// The sign of each component.
+	    pub fn signum(&self) -> Self {
+		$point { $($c: self.$c.signum()),+ }
+	    }
+	}
+
+	impl<T: Add<Output = T>> Add for $point<T> {
+	    type Output = Self;
+
+	    fn add(self, other: Self) -> Self {
+		$point { $($c: self.$c + other.$c),+ }
+	    }
+	}
+
+	impl<T: Sub<Output = T>> Sub for $point<T> {
+	    type Output = Self;
+
+	    fn sub(self, other: Self) -> Self {
+		$point { $($c: self.$c - other.$c),+ }
+	    }
+	}
+
+	impl<T: AddAssign> AddAssign for $point<T> {
+	    fn add_assign(&mut self, other: Self) {
+		$(self.$c += other.$c;)+
+	    }
+	}
+
+	impl<T: SubAssign> SubAssign for $point<T> {
+	    fn sub_assign(&mut self, other: Self) {
+		$(self.$c -= other.$c;)+
+	    }
+	}
+
+	impl<T: Neg<Output = T>> Neg for $point<T> {
+	    type Output = Self;
+
+	    fn neg(self) -> Self {
+		$point { $($c: -self.$c),+ }
+	    }
+	}
+
+	impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+	    type Output = Self;
+
+	    fn mul(self, k: T) -> Self {
+		$point { $($c: self.$c * k),+ }
+	    }
+	}
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+/// Points in the plane are ordered the way a grid is read: by row `y`
+/// first, then by column `x`.
+impl<T: Ord> Ord for Point2<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+	self.y.cmp(&other.y).then_with(|| self.x.cmp(&other.x))
+    }
+}
+
+impl<T: Ord> PartialOrd for Point2<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+	Some(self.cmp(other))
+    }
+}
+
+/// Points in space are ordered by `x`, then `y`, then `z`.
+impl<T: Ord> Ord for Point3<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+	self.x.cmp(&other.x)
+	    .then_with(|| self.y.cmp(&other.y))
+	    .then_with(|| self.z.cmp(&other.z))
+    }
+}
+
+impl<T: Ord> PartialOrd for Point3<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+	Some(self.cmp(other))
+    }
+}
+
+impl Point2<isize> {
+    /// The point of the signed grid coordinates `(row, col)`, as taken by
+    /// `Grid::get` and `SparseGrid`.
+    pub const fn from_row_col(row: isize, col: isize) -> Self {
+	Point2 { x: col, y: row }
+    }
+
+    /// The signed grid coordinates `(row, col)` of this point.
+    pub const fn to_row_col(self) -> (isize, isize) {
+	(self.y, self.x)
+    }
+
+    /// The grid cell at this point, if it is not left of or above the grid.
+    pub fn to_pos(self) -> Option<Pos> {
+	Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+}
+
+/// The four directions along the axes of a grid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// All directions, clockwise from `Up`.
+    pub const ALL: [Direction4; 4] = [Direction4::Up, Direction4::Right, Direction4::Down, Direction4::Left];
+
+    fn index(self) -> usize {
+	self as usize
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+	Self::ALL[(self.index() + 1) % 4]
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+	Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+	Self::ALL[(self.index() + 2) % 4]
+    }
+
+    /// The step `(row, column)` of one move on a grid.
+    pub fn offset(self) -> (isize, isize) {
+	match self {
+	    Direction4::Up => UP,
+	    Direction4::Right => RIGHT,
+	    Direction4::Down => DOWN,
+	    Direction4::Left => LEFT,
+	}
+    }
+
+    /// The step of one move as a point, with `y` growing downwards.
+    pub fn unit(self) -> Point2<isize> {
+	let (row, col) = self.offset();
+	Point2::from_row_col(row, col)
+    }
+}
+
+/// The four directions along the axes and the four diagonals, named after
+/// the points of the compass with north up.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from `N`.
+    pub const ALL: [Direction8; 8] = [Direction8::N, Direction8::NE, Direction8::E, Direction8::SE,
+				      Direction8::S, Direction8::SW, Direction8::W, Direction8::NW];
+
+    fn index(self) -> usize {
+	self as usize
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+	Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+	Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+	Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// The step `(row, column)` of one move on a grid.
+    pub fn offset(self) -> (isize, isize) {
+	match self {
+	    Direction8::N => (-1, 0),
+	    Direction8::NE => (-1, 1),
+	    Direction8::E => (0, 1),
+	    Direction8::SE => (1, 1),
+	    Direction8::S => (1, 0),
+	    Direction8::SW => (1, -1),
+	    Direction8::W => (0, -1),
+	    Direction8::NW => (-1, -1),
+	}
+    }
+
+    /// The step of one move as a point, with `y` growing downwards.
+    pub fn unit(self) -> Point2<isize> {
+	let (row, col) = self.offset();
+	Point2::from_row_col(row, col)
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Self {
+	Self::ALL[2 * dir.index()]
+    }
+}
@@ -0,0 +1,740 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut, Range};
+use std::iter::{Iterator, DoubleEndedIterator, ExactSizeIterator};
+use std::fmt;
+use nom::error::ErrorKind;
+use crate::Error;
+
+/// A cell of a grid as `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// Steps between neighbouring cells as `(rows, columns)`. Coordinates that
+/// may leave the grid are signed.
+pub const UP: (isize, isize) = (-1, 0);
+pub const DOWN: (isize, isize) = (1, 0);
+pub const LEFT: (isize, isize) = (0, -1);
+pub const RIGHT: (isize, isize) = (0, 1);
+
+pub const DIRECTIONS4: [(isize, isize); 4] = [UP, DOWN, LEFT, RIGHT];
+pub const DIRECTIONS8: [(isize, isize); 8] = [UP, DOWN, LEFT, RIGHT, (-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+/// Why a text could not be read as a grid. Lines and columns are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    Ragged { line: usize, expected: usize, found: usize },
+    InvalidChar { line: usize, column: usize, c: char },
+}
+
+impl GridError {
+    /// Turns the error into a parse error of `day`, quoting the offending
+    /// line of `text`.
+    pub fn at_day(&self, day: usize, text: &str) -> Error {
+	let line_text = |line: usize| text.lines().nth(line - 1).unwrap_or("");
+	match *self {
+	    GridError::Empty => Error::parse(day, 1, "", ErrorKind::Eof),
+	    GridError::Ragged { line, .. } => Error::parse(day, line, line_text(line), ErrorKind::LengthValue),
+	    GridError::InvalidChar { line, .. } => Error::parse(day, line, line_text(line), ErrorKind::Char),
+	}
+    }
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+	match self {
+	    GridError::Empty => write!(f, "the grid is empty"),
+	    GridError::Ragged { line, expected, found } =>
+		write!(f, "line {:} has {:} cells instead of {:}", line, found, expected),
+	    GridError::InvalidChar { line, column, c } =>
+		write!(f, "unexpected {:?} at line {:}, column {:}", c, line, column),
+	}
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// Positions of the marker characters found while parsing a grid.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: HashMap<char, Vec<(usize, usize)>>,
+}
+
+impl Markers {
+    /// Every position of `marker`, in reading order.
+    pub fn get(&self, marker: char) -> &[(usize, usize)] {
+	self.positions.get(&marker).map_or(&[], |v| v.as_slice())
+    }
+
+    /// The position of `marker` if it occurs exactly once.
+    pub fn single(&self, marker: char) -> Option<(usize, usize)> {
+	match self.get(marker) {
+	    [position] => Some(*position),
+	    _ => None,
+	}
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    pub dims: (usize, usize),
+    pub array: Vec<T>
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+	Self::new()
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new() -> Self {
+	Grid { array: Vec::new(), dims: (0,0) }
+    }
+   
+    /// Builds a grid from equally long, non-empty rows.
+    pub fn from_rows(data: Vec<Vec<T>>) -> Result<Self, GridError> {
+	let cols = match data.first() {
+	    Some(row) if !row.is_empty() => row.len(),
+	    _ => return Err(GridError::Empty),
+	};
+	let dims = (data.len(), cols);
+	let mut array = Vec::with_capacity(dims.0 * dims.1);
+	for (i, mut v) in data.into_iter().enumerate() {
+	    if v.len() != cols {
+		return Err(GridError::Ragged { line: i + 1, expected: cols, found: v.len() });
+	    }
+	    array.append(&mut v)
+	}
+	Ok(Grid { array, dims })
+    }
+
+    /// Builds a `dims.0 x dims.1` grid whose cell `(r, c)` is `f((r, c))`.
+    pub fn from_fn(dims: (usize, usize), mut f: impl FnMut((usize, usize)) -> T) -> Self {
+	let mut array = Vec::with_capacity(dims.0 * dims.1);
+	for r in 0..dims.0 {
+	    for c in 0..dims.1 {
+		array.push(f((r, c)));
+	    }
+	}
+	Grid { array, dims }
+    }
+
+    /// Reads one row per line of `text`, mapping every character with `f`.
+    /// A character that `f` rejects with `None` is an error, and so are
+    /// lines of different lengths and a text without cells.
+    pub fn parse(text: &str, f: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+	Self::parse_with_markers(text, "", f).map(|(grid, _)| grid)
+    }
+
+    /// Like `parse`, but also records where each character of `markers`
+    /// occurs. Markers are still mapped with `f`, so that `S` can stand on
+    /// an ordinary cell.
+    pub fn parse_with_markers(text: &str, markers: &str, mut f: impl FnMut(char) -> Option<T>)
+			      -> Result<(Self, Markers), GridError> {
+	let mut found = Markers::default();
+	let mut rows = Vec::new();
+	for (i, line) in text.lines().enumerate() {
+	    let mut row = Vec::with_capacity(line.len());
+	    for (j, c) in line.chars().enumerate() {
+		if markers.contains(c) {
+		    found.positions.entry(c).or_default().push((i, j));
+		}
+		row.push(f(c).ok_or(GridError::InvalidChar { line: i + 1, column: j + 1, c })?);
+	    }
+	    rows.push(row);
+	}
+	Ok((Self::from_rows(rows)?, found))
+    }
+
+    /// Formats the grid one line per row, drawing every cell with `f`.
+    pub fn display<F: Fn(&T) -> char>(&self, f: F) -> GridDisplay<'_, T, F> {
+	GridDisplay { grid: self, f }
+    }
+
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+	self.display(f).to_string()
+    }
+
+    /// Whether `(r, c)` lies inside the grid.
+    pub fn in_bounds(&self, (r, c): (isize, isize)) -> bool {
+	r >= 0 && c >= 0 && (r as usize) < self.dims.0 && (c as usize) < self.dims.1
+    }
+
+    /// The cell at `(r, c)`, or `None` outside of the grid.
+    pub fn get(&self, pos: (isize, isize)) -> Option<&T> {
+	if self.in_bounds(pos) {
+	    Some(&self.array[pos.0 as usize * self.dims.1 + pos.1 as usize])
+	} else {
+	    None
+	}
+    }
+
+    pub fn get_mut(&mut self, pos: (isize, isize)) -> Option<&mut T> {
+	if self.in_bounds(pos) {
+	    Some(&mut self.array[pos.0 as usize * self.dims.1 + pos.1 as usize])
+	} else {
+	    None
+	}
+    }
+
+    /// The cell one `step` away from `pos`, if that is inside the grid.
+    pub fn step(&self, (r, c): Pos, step: (isize, isize)) -> Option<Pos> {
+	let next = (r as isize + step.0, c as isize + step.1);
+	if self.in_bounds(next) {
+	    Some((next.0 as usize, next.1 as usize))
+	} else {
+	    None
+	}
+    }
+
+    /// The up to four cells sharing an edge with `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+	DIRECTIONS4.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `pos`.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+	DIRECTIONS8.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// All positions in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+	let cols = self.dims.1;
+	(0..self.dims.0 * cols).map(move |i| (i / cols, i % cols))
+    }
+
+    /// All cells with their positions, in reading order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &T)> {
+	self.positions().zip(self.array.iter())
+    }
+
+    /// The cells seen when walking from `from` in steps of `step` until the
+    /// edge, without `from` itself. `step` must not be `(0, 0)`.
+    pub fn ray(&self, from: Pos, step: (isize, isize)) -> Ray<'_, T> {
+	Ray { grid: self, pos: from, step }
+    }
+
+    pub fn row(&self, i: usize) -> GridRowIter<'_, T> {
+	GridRowIter { array: &self.array,
+		      dims: self.dims,
+		      row: i,
+		      front: self.dims.1 * i,
+		      back: Some(self.dims.1 * (i+1) - 1),
+		      seen: 0
+	}
+    }
+
+    pub fn col(&self, j: usize) -> GridColIter<'_, T> {
+	GridColIter { array: &self.array,
+		      dims: self.dims,
+		      front: j,
+		      back: Some(self.dims.1 * (self.dims.0 - 1) + j),
+		      seen: 0
+	}
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+	self[i].iter_mut()
+    }
+
+    pub fn col_mut(&mut self, j: usize) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+	assert!(j < self.dims.1, "column {:} outside of a {:?} grid", j, self.dims);
+	self.array.iter_mut().skip(j).step_by(self.dims.1)
+    }
+}
+
+/// Rearrangements of a whole grid. They return new grids and leave `self`
+/// as it is.
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal: rows become columns.
+    pub fn transpose(&self) -> Self {
+	Grid::from_fn((self.dims.1, self.dims.0), |(r, c)| self[(c, r)].clone())
+    }
+
+    /// Turns the grid a quarter clockwise: the first column becomes the
+    /// first row, read bottom to top.
+    pub fn rotate_cw(&self) -> Self {
+	let rows = self.dims.0;
+	Grid::from_fn((self.dims.1, rows), |(r, c)| self[(rows - 1 - c, r)].clone())
+    }
+
+    /// Turns the grid a quarter counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+	let cols = self.dims.1;
+	Grid::from_fn((cols, self.dims.0), |(r, c)| self[(c, cols - 1 - r)].clone())
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_h(&self) -> Self {
+	let cols = self.dims.1;
+	Grid::from_fn(self.dims, |(r, c)| self[(r, cols - 1 - c)].clone())
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_v(&self) -> Self {
+	let rows = self.dims.0;
+	Grid::from_fn(self.dims, |(r, c)| self[(rows - 1 - r, c)].clone())
+    }
+
+    /// Copies the cells in `rows` x `cols` into a grid of their own, which
+    /// can then be turned and flipped independently of `self`.
+    pub fn window(&self, rows: Range<usize>, cols: Range<usize>) -> Self {
+	assert!(rows.start <= rows.end && rows.end <= self.dims.0
+		&& cols.start <= cols.end && cols.end <= self.dims.1,
+		"window {:?} x {:?} outside of a {:?} grid", rows, cols, self.dims);
+	Grid::from_fn((rows.len(), cols.len()), |(r, c)| self[(rows.start + r, cols.start + c)].clone())
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct GridRowIter<'a, T> {
+    array: &'a [T],
+    dims: (usize, usize),
+    row: usize,
+    front: usize,
+    back: Option<usize>,
+    seen: usize
+}
+
+impl<'a, T: std::fmt::Debug> Iterator for GridRowIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+	if self.front == self.dims.1 * (self.row + 1) {
+	    None
+	} else {
+	    let v = &self.array[self.front];
+	    self.front += 1;
+	    self.seen += 1;
+	    Some(v)
+	}
+    }
+}
+
+impl<'a, T: std::fmt::Debug> DoubleEndedIterator for GridRowIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+	if let Some(back) = self.back {
+	    let v = &self.array[back];
+	    self.back = if back > self.dims.1 * self.row {
+		Some(back - 1)
+	    } else {
+		None
+	    };
+	    self.seen += 1;	    
+	    Some(v)
+	} else {
+	    None
+	}
+    }		
+}
+
+impl<'a, T: std::fmt::Debug> ExactSizeIterator for GridRowIter<'a, T> {
+    fn len(&self) -> usize {
+	self.dims.1 - self.seen
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct GridColIter<'a, T> {
+    array: &'a [T],
+    dims: (usize, usize),
+    front: usize,
+    back: Option<usize>,
+    seen: usize
+}
+
+impl<'a, T: std::fmt::Debug> Iterator for  GridColIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+	if self.front >= self.dims.1 * self.dims.0 {
+	    None
+	} else {
+	    let v = &self.array[self.front];	    
+	    self.front += self.dims.1;
+	    self.seen += 1;
+	    Some(v)
+	}
+    }
+}
+
+impl<'a, T: std::fmt::Debug> DoubleEndedIterator for  GridColIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+	if let Some(back) = self.back {
+	    let v = &self.array[back];	    
+	    self.back = if back >= self.dims.1 {
+		Some(back - self.dims.1)
+	    } else {
+		None
+	    };
+	    self.seen += 1;
+	    Some(v)
+	} else {
+	    None
+	}
+    }
+}
+
+impl<'a, T: std::fmt::Debug> ExactSizeIterator for GridColIter<'a, T> {
+    fn len(&self) -> usize {
+	self.dims.0 - self.seen
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+	let start = row * self.dims.1;
+	&self.array[start..start + self.dims.1]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+	let start = row * self.dims.1;
+	&mut self.array[start..start + self.dims.1]
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): Pos) -> &T {
+	assert!(r < self.dims.0 && c < self.dims.1, "position {:?} outside of a {:?} grid", (r, c), self.dims);
+	&self.array[r * self.dims.1 + c]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (r, c): Pos) -> &mut T {
+	assert!(r < self.dims.0 && c < self.dims.1, "position {:?} outside of a {:?} grid", (r, c), self.dims);
+	&mut self.array[r * self.dims.1 + c]
+    }
+}
+
+/// Walks from a cell in a fixed direction; see `Grid::ray`.
+#[derive(Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Pos,
+    step: (isize, isize),
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Pos, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+	self.pos = self.grid.step(self.pos, self.step)?;
+	Some((self.pos, &self.grid[self.pos]))
+    }
+}
+
+/// Draws a grid with a cell-to-character mapping; see `Grid::display`.
+pub struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    f: F,
+}
+
+impl<T, F: Fn(&T) -> char> fmt::Display for GridDisplay<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+	for r in 0..self.grid.dims.0 {
+	    if r > 0 {
+		writeln!(f)?;
+	    }
+	    for cell in &self.grid[r] {
+		write!(f, "{:}", (self.f)(cell))?;
+	    }
+	}
+	Ok(())
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+	write!(f, "{:}", self.display(|&c| c))
+    }
+}
+
+/// Smallest rectangle containing a set of positions, with inclusive corners.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: (isize, isize),
+    pub max: (isize, isize),
+}
+
+impl Bounds {
+    pub fn new(pos: (isize, isize)) -> Self {
+	Bounds { min: pos, max: pos }
+    }
+
+    pub fn contains(&self, (r, c): (isize, isize)) -> bool {
+	self.min.0 <= r && r <= self.max.0 && self.min.1 <= c && c <= self.max.1
+    }
+
+    /// Grows the rectangle just enough to contain `pos`.
+    pub fn extend(&mut self, (r, c): (isize, isize)) {
+	self.min = (self.min.0.min(r), self.min.1.min(c));
+	self.max = (self.max.0.max(r), self.max.1.max(c));
+    }
+
+    /// Number of rows and columns.
+    pub fn dims(&self) -> (usize, usize) {
+	((self.max.0 - self.min.0 + 1) as usize, (self.max.1 - self.min.1 + 1) as usize)
+    }
+
+    pub fn area(&self) -> usize {
+	let (rows, cols) = self.dims();
+	rows * cols
+    }
+
+    fn on_edge(&self, (r, c): (isize, isize)) -> bool {
+	r == self.min.0 || r == self.max.0 || c == self.min.1 || c == self.max.1
+    }
+}
+
+/// Cells on an unbounded plane, indexed by signed `(row, column)`. Only
+/// occupied cells are stored. The bounding box is kept up to date on every
+/// insert; removing a cell from its edge costs a scan of all cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(isize, isize), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+	Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+	SparseGrid { cells: HashMap::new(), bounds: None }
+    }
+
+    pub fn len(&self) -> usize {
+	self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+	self.cells.is_empty()
+    }
+
+    /// The bounding box of all occupied cells, `None` when there are none.
+    pub fn bounds(&self) -> Option<Bounds> {
+	self.bounds
+    }
+
+    pub fn contains(&self, pos: (isize, isize)) -> bool {
+	self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: (isize, isize)) -> Option<&T> {
+	self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: (isize, isize)) -> Option<&mut T> {
+	self.cells.get_mut(&pos)
+    }
+
+    /// Stores `value` at `pos` and returns the value that was there.
+    pub fn insert(&mut self, pos: (isize, isize), value: T) -> Option<T> {
+	match &mut self.bounds {
+	    Some(bounds) => bounds.extend(pos),
+	    None => self.bounds = Some(Bounds::new(pos)),
+	}
+	self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: (isize, isize)) -> Option<T> {
+	let value = self.cells.remove(&pos)?;
+	if self.bounds.is_some_and(|b| b.on_edge(pos)) {
+	    let mut positions = self.cells.keys();
+	    self.bounds = positions.next().map(|&first| {
+		let mut bounds = Bounds::new(first);
+		for &pos in positions {
+		    bounds.extend(pos);
+		}
+		bounds
+	    });
+	}
+	Some(value)
+    }
+
+    /// The occupied cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((isize, isize), &T)> {
+	self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+	self.cells.keys().copied()
+    }
+
+    /// The occupied cells among the four that share an edge with `pos`.
+    pub fn neighbours4(&self, pos: (isize, isize)) -> impl Iterator<Item = ((isize, isize), &T)> {
+	self.occupied(pos, &DIRECTIONS4)
+    }
+
+    /// The occupied cells among the eight that share an edge or a corner
+    /// with `pos`.
+    pub fn neighbours8(&self, pos: (isize, isize)) -> impl Iterator<Item = ((isize, isize), &T)> {
+	self.occupied(pos, &DIRECTIONS8)
+    }
+
+    fn occupied<'a>(&'a self, (r, c): (isize, isize), steps: &'a [(isize, isize)])
+		    -> impl Iterator<Item = ((isize, isize), &'a T)> {
+	steps.iter().filter_map(move |&(dr, dc)| {
+	    let next = (r + dr, c + dc);
+	    self.cells.get(&next).map(|value| (next, value))
+	})
+    }
+
+    /// Draws the bounding box, with `empty` for unoccupied cells.
+    pub fn render(&self, empty: char, f: impl Fn(&T) -> char) -> String {
+	match self.bounds {
+	    None => String::new(),
+	    Some(bounds) => Grid::from_fn(bounds.dims(), |(r, c)| {
+		let pos = (bounds.min.0 + r as isize, bounds.min.1 + c as isize);
+		self.cells.get(&pos).map_or(empty, &f)
+	    }).to_string(),
+	}
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Copies the bounding box into a dense grid, filling unoccupied cells
+    /// with `empty`. Also returns the position of the grid's `(0, 0)`.
+    pub fn to_grid(&self, empty: T) -> (Grid<T>, (isize, isize)) {
+	match self.bounds {
+	    None => (Grid::new(), (0, 0)),
+	    Some(bounds) => {
+		let grid = Grid::from_fn(bounds.dims(), |(r, c)| {
+		    let pos = (bounds.min.0 + r as isize, bounds.min.1 + c as isize);
+		    self.cells.get(&pos).unwrap_or(&empty).clone()
+		});
+		(grid, bounds.min)
+	    }
+	}
+    }
+
+    /// Takes the cells of `grid` for which `keep` holds, placing the grid's
+    /// `(0, 0)` at `origin`.
+    pub fn from_grid(grid: &Grid<T>, origin: (isize, isize), keep: impl Fn(&T) -> bool) -> Self {
+	grid.enumerate()
+	    .filter(|(_, value)| keep(value))
+	    .map(|((r, c), value)| ((origin.0 + r as isize, origin.1 + c as isize), value.clone()))
+	    .collect()
+    }
+}
+
+impl<T> FromIterator<((isize, isize), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((isize, isize), T)>>(iter: I) -> Self {
+	let mut grid = SparseGrid::new();
+	grid.extend(iter);
+	grid
+    }
+}
+
+impl<T> Extend<((isize, isize), T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = ((isize, isize), T)>>(&mut self, iter: I) {
+	for (pos, value) in iter {
+	    self.insert(pos, value);
+	}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a b c
+    // d e f
+    fn grid() -> Grid<char> {
+	Grid::parse("abc\ndef", Some).unwrap()
+    }
+
+    fn cells(text: &str) -> Grid<char> {
+	Grid::parse(text, Some).unwrap()
+    }
+
+    #[test]
+    fn transforms() {
+	assert_eq!(grid().transpose(), cells("ad\nbe\ncf"));
+	assert_eq!(grid().rotate_cw(), cells("da\neb\nfc"));
+	assert_eq!(grid().rotate_ccw(), cells("cf\nbe\nad"));
+	assert_eq!(grid().flip_h(), cells("cba\nfed"));
+	assert_eq!(grid().flip_v(), cells("def\nabc"));
+	assert_eq!(grid().rotate_cw().rotate_ccw(), grid());
+	assert_eq!(grid().rotate_cw().rotate_cw(), grid().flip_h().flip_v());
+    }
+
+    #[test]
+    fn mutable_rows_and_columns() {
+	let mut g = grid();
+	for (i, c) in g.row_mut(1).rev().enumerate() {
+	    *c = char::from(b'0' + i as u8);
+	}
+	assert_eq!(g, cells("abc\n210"));
+	for c in g.col_mut(2) {
+	    *c = '*';
+	}
+	assert_eq!(g, cells("ab*\n21*"));
+	assert_eq!(g.col_mut(1).len(), 2);
+    }
+
+    #[test]
+    fn window() {
+	assert_eq!(grid().window(0..2, 1..3), cells("bc\nef"));
+	assert_eq!(grid().window(1..2, 0..3).rotate_cw(), cells("d\ne\nf"));
+    }
+
+    // Cells at (-2, -1), (0, 3) and (1, 0):
+    // x . . . .
+    // . . . . .
+    // . . . . x
+    // . x . . .
+    fn sparse() -> SparseGrid<char> {
+	[((-2, -1), 'a'), ((0, 3), 'b'), ((1, 0), 'c')].into_iter().collect()
+    }
+
+    #[test]
+    fn sparse_insert_tracks_bounds() {
+	let mut g = sparse();
+	assert_eq!(g.bounds(), Some(Bounds { min: (-2, -1), max: (1, 3) }));
+	assert_eq!(g.bounds().unwrap().dims(), (4, 5));
+	assert_eq!(g.insert((0, 3), 'B'), Some('b'));
+	assert_eq!(g.insert((5, -4), 'd'), None);
+	assert_eq!(g.bounds(), Some(Bounds { min: (-2, -4), max: (5, 3) }));
+	assert_eq!(g.len(), 4);
+    }
+
+    #[test]
+    fn sparse_remove_shrinks_bounds() {
+	let mut g = sparse();
+	// Not on the edge: the bounds stay
+	g.insert((0, 0), 'x');
+	assert_eq!(g.remove((0, 0)), Some('x'));
+	assert_eq!(g.bounds(), Some(Bounds { min: (-2, -1), max: (1, 3) }));
+	assert_eq!(g.remove((0, 0)), None);
+
+	assert_eq!(g.remove((-2, -1)), Some('a'));
+	assert_eq!(g.bounds(), Some(Bounds { min: (0, 0), max: (1, 3) }));
+	g.remove((0, 3));
+	assert_eq!(g.bounds(), Some(Bounds::new((1, 0))));
+	g.remove((1, 0));
+	assert_eq!(g.bounds(), None);
+	assert!(g.is_empty());
+	assert_eq!(g.render('.', |&c| c), "");
+    }
+
+    #[test]
+    fn sparse_render_and_to_grid() {
+	let g = sparse();
+	assert_eq!(g.render('.', |&c| c), "a....\n.....\n....b\n.c...");
+	let (dense, origin) = g.to_grid('.');
+	assert_eq!(origin, (-2, -1));
+	assert_eq!(dense, cells("a....\n.....\n....b\n.c..."));
+	assert_eq!(SparseGrid::from_grid(&dense, origin, |&c| c != '.'), g);
+    }
+}
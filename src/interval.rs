@@ -0,0 +1,249 @@
+use std::fmt;
+
+/// The integers from `start` to `end`, both included.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// Returns `None` when `end` comes before `start`.
+    pub fn new(start: i64, end: i64) -> Option<Self> {
+	(start <= end).then_some(Interval { start, end })
+    }
+
+    /// Number of integers in the interval, which is 2^64 for the whole
+    /// range of `i64` and so does not fit in a `u64`.
+    pub fn size(&self) -> u128 {
+	self.start.abs_diff(self.end) as u128 + 1
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+	self.start <= x && x <= self.end
+    }
+
+    /// Whether every integer of `other` is in `self`.
+    pub fn covers(&self, other: &Self) -> bool {
+	self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+	self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+	Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+	write!(f, "{:}..={:}", self.start, self.end)
+    }
+}
+
+/// A set of integers, stored as sorted disjoint intervals. Intervals that
+/// overlap or touch are merged on insertion.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+	IntervalSet { intervals: Vec::new() }
+    }
+
+    pub fn clear(&mut self) {
+	self.intervals.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+	self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set. The intervals are disjoint, so this
+    /// is at most 2^64 and the sum cannot overflow.
+    pub fn len(&self) -> u128 {
+	self.intervals.iter().map(Interval::size).sum()
+    }
+
+    /// The disjoint intervals of the set in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+	self.intervals.iter()
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+	let i = self.intervals.partition_point(|r| r.end < x);
+	self.intervals.get(i).is_some_and(|r| r.contains(x))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+	let Interval { mut start, mut end } = interval;
+	// The intervals in lo..hi overlap or touch the new one
+	let lo = self.intervals.partition_point(|r| r.end.saturating_add(1) < start);
+	let hi = self.intervals.partition_point(|r| r.start.saturating_sub(1) <= end);
+	if lo < hi {
+	    start = start.min(self.intervals[lo].start);
+	    end = end.max(self.intervals[hi - 1].end);
+	}
+	self.intervals.splice(lo..hi, [Interval { start, end }]);
+    }
+
+    pub fn remove(&mut self, interval: Interval) {
+	let lo = self.intervals.partition_point(|r| r.end < interval.start);
+	let hi = self.intervals.partition_point(|r| r.start <= interval.end);
+	if lo == hi {
+	    return;
+	}
+	let first = self.intervals[lo];
+	let last = self.intervals[hi - 1];
+	// What sticks out of `interval` on either side survives
+	let left = interval.start.checked_sub(1).and_then(|end| Interval::new(first.start, end));
+	let right = interval.end.checked_add(1).and_then(|start| Interval::new(start, last.end));
+	self.intervals.splice(lo..hi, left.into_iter().chain(right));
+    }
+
+    /// The maximal intervals of `within` that are not in the set.
+    pub fn gaps(&self, within: Interval) -> Vec<Interval> {
+	let mut gaps = Vec::new();
+	let mut next = within.start;
+	for r in &self.intervals {
+	    if r.end < within.start {
+		continue;
+	    }
+	    if r.start > within.end {
+		break;
+	    }
+	    if r.start > next {
+		gaps.push(Interval { start: next, end: r.start - 1 });
+	    }
+	    next = next.max(r.end.saturating_add(1));
+	}
+	if next <= within.end {
+	    gaps.push(Interval { start: next, end: within.end });
+	}
+	gaps
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+	let mut union = self.clone();
+	union.extend(other.iter().copied());
+	union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+	let (mut i, mut j) = (0, 0);
+	let mut intervals = Vec::new();
+	while i < self.intervals.len() && j < other.intervals.len() {
+	    let (a, b) = (self.intervals[i], other.intervals[j]);
+	    intervals.extend(a.intersection(&b));
+	    // Move past whichever interval ends first
+	    if a.end < b.end {
+		i += 1;
+	    } else {
+		j += 1;
+	    }
+	}
+	IntervalSet { intervals }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+	let mut set = IntervalSet::new();
+	set.extend(iter);
+	set
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, iter: I) {
+	for interval in iter {
+	    self.insert(interval);
+	}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iv(start: i64, end: i64) -> Interval {
+	Interval::new(start, end).unwrap()
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+	intervals.iter().map(|&(start, end)| iv(start, end)).collect()
+    }
+
+    fn intervals(set: &IntervalSet) -> Vec<(i64, i64)> {
+	set.iter().map(|r| (r.start, r.end)).collect()
+    }
+
+    #[test]
+    fn insert_merges_touching_and_overlapping() {
+	assert_eq!(intervals(&set(&[(1, 3), (4, 6)])), [(1, 6)]);
+	assert_eq!(intervals(&set(&[(1, 3), (5, 6)])), [(1, 3), (5, 6)]);
+	assert_eq!(intervals(&set(&[(5, 6), (1, 3), (2, 5)])), [(1, 6)]);
+	assert_eq!(intervals(&set(&[(0, 10), (2, 3)])), [(0, 10)]);
+	assert_eq!(intervals(&set(&[(2, 3), (6, 7), (0, 10)])), [(0, 10)]);
+	assert_eq!(intervals(&set(&[(i64::MIN, 0), (1, i64::MAX)])), [(i64::MIN, i64::MAX)]);
+    }
+
+    #[test]
+    fn len_and_contains() {
+	let s = set(&[(1, 3), (7, 7), (10, 12)]);
+	assert_eq!(s.len(), 7);
+	assert!(s.contains(7) && s.contains(12));
+	assert!(!s.contains(0) && !s.contains(5) && !s.contains(13));
+	assert!(IntervalSet::new().is_empty());
+    }
+
+    #[test]
+    fn len_of_whole_range() {
+	let s = set(&[(i64::MIN, -1), (0, i64::MAX)]);
+	assert_eq!(s.len(), 1 << 64);
+	assert_eq!(set(&[(i64::MIN, i64::MAX)]).len(), 1 << 64);
+    }
+
+    #[test]
+    fn remove_splits_and_spans() {
+	let mut s = set(&[(0, 10)]);
+	s.remove(iv(3, 4));
+	assert_eq!(intervals(&s), [(0, 2), (5, 10)]);
+
+	let mut s = set(&[(0, 2), (4, 6), (8, 10), (12, 14)]);
+	s.remove(iv(1, 9));
+	assert_eq!(intervals(&s), [(0, 0), (10, 10), (12, 14)]);
+
+	let mut s = set(&[(0, 2), (4, 6)]);
+	s.remove(iv(-5, 20));
+	assert!(s.is_empty());
+
+	let mut s = set(&[(0, 2), (6, 8)]);
+	s.remove(iv(3, 5));
+	assert_eq!(intervals(&s), [(0, 2), (6, 8)]);
+    }
+
+    #[test]
+    fn gaps() {
+	let s = set(&[(2, 3), (6, 8), (20, 30)]);
+	let gaps: Vec<_> = s.gaps(iv(0, 10)).iter().map(|r| (r.start, r.end)).collect();
+	assert_eq!(gaps, [(0, 1), (4, 5), (9, 10)]);
+	assert!(s.gaps(iv(6, 8)).is_empty());
+	assert_eq!(s.gaps(iv(10, 15)), [iv(10, 15)]);
+    }
+
+    #[test]
+    fn intersection_and_union() {
+	let a = set(&[(0, 5), (10, 15)]);
+	let b = set(&[(3, 12), (14, 20)]);
+	assert_eq!(intervals(&a.intersection(&b)), [(3, 5), (10, 12), (14, 15)]);
+	assert_eq!(intervals(&a.union(&b)), [(0, 20)]);
+	assert!(a.intersection(&set(&[(6, 9)])).is_empty());
+	assert_eq!(iv(0, 5).intersection(&iv(6, 9)), None);
+	assert!(iv(0, 5).covers(&iv(1, 5)) && !iv(0, 5).covers(&iv(1, 6)));
+    }
+}
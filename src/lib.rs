@@ -1,174 +1,234 @@
-use std::ops::{Index, IndexMut};
-use std::iter::{Iterator, DoubleEndedIterator, ExactSizeIterator};
+use std::fmt;
+use std::time::{Duration, Instant};
+use nom::{combinator::all_consuming, error::ErrorKind, IResult};
+
+pub mod cycle;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod output;
+pub mod parse;
+pub mod registry;
+pub mod search;
+pub mod y2022;
+
+pub use geometry::{Direction4, Direction8, Point2, Point3};
+pub use grid::{Grid, SparseGrid};
+pub use interval::{Interval, IntervalSet};
+pub use registry::Registry;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input of `day` could not be parsed at `line` (1-based).
+    Parse { day: usize, line: usize, text: String, kind: ErrorKind },
+    /// The input parsed, but the puzzle could not be solved with it.
+    Solve { day: usize, message: String },
+    /// The input file could not be read.
+    Io { path: String, message: String },
+}
 
-pub trait Puzzle {
-    fn load_input(&mut self);
+pub type Result<T> = std::result::Result<T, Error>;
 
-    fn part1(&self) -> String;
+impl Error {
+    pub fn parse(day: usize, line: usize, text: &str, kind: ErrorKind) -> Self {
+	Error::Parse { day, line, text: text.to_string(), kind }
+    }
 
-    fn part2(&self) -> String;
+    pub fn solve(day: usize, message: impl Into<String>) -> Self {
+	Error::Solve { day, message: message.into() }
+    }
+}
 
-    fn run(&mut self) -> (String, String) {
-	self.load_input();
-	(self.part1(), self.part2())
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+	match self {
+	    Error::Parse { day, line, text, kind } =>
+		write!(f, "day {:02}, line {:}: cannot parse {:?} ({:})", day, line, text, kind.description()),
+	    Error::Solve { day, message } =>
+		write!(f, "day {:02}: {:}", day, message),
+	    Error::Io { path, message } =>
+		write!(f, "cannot read {:}: {:}", path, message),
+	}
     }
 }
 
-pub fn print_day(year: usize, day: usize, (part1, part2): (String, String))
+impl std::error::Error for Error {}
+
+/// Runs `parser` over `text`, which must be consumed completely. Failures are
+/// reported as `Error::Parse` for the given day and (1-based) line number.
+pub fn parse_complete<'a, T, P>(day: usize, line: usize, text: &'a str, parser: P) -> Result<T>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, T>
 {
-    println!();
-    println!("----------- AoC {:04}, Day {:02} -----------", year, day);
-    println!("part 1: {:}", part1);
-    println!("part 2: {:}", part2);    
+    match all_consuming(parser)(text) {
+	Ok((_, value)) => Ok(value),
+	Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => Err(Error::parse(day, line, text, e.code)),
+	Err(nom::Err::Incomplete(_)) => Err(Error::parse(day, line, text, ErrorKind::Complete)),
+    }
 }
 
-#[derive(Debug, Clone)]
-pub struct Grid<T> {
-    pub dims: (usize, usize),
-    pub array: Vec<T>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
 }
 
-impl<T> Grid<T> {
-    pub fn new() -> Self {
-	Grid { array: Vec::new(), dims: (0,0) }
-    }
-   
-    pub fn from_rows(data: Vec<Vec<T>>) -> Self {
-	let dims = (data.len(), data[0].len());
-	let mut array = Vec::with_capacity(dims.0 * dims.1);
-	for mut v in data {
-	    array.append(&mut v)
-	}
-	Grid { array, dims }
-    }
+/// Answers of a single run together with the wall-clock time of each step.
+/// Parts that were not asked for are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Solution {
+    pub part1: Option<(Answer, Duration)>,
+    pub part2: Option<(Answer, Duration)>,
+    pub load_time: Duration,
+}
 
-    pub fn row(&self, i: usize) -> GridRowIter<T> {
-	GridRowIter { array: &self.array,
-		      dims: self.dims,
-		      row: i,
-		      front: self.dims.1 * i,
-		      back: Some(self.dims.1 * (i+1) - 1),
-		      seen: 0
-	}
+impl Solution {
+    pub fn total_time(&self) -> Duration {
+	self.load_time
+	    + self.part1.as_ref().map_or(Duration::ZERO, |(_, t)| *t)
+	    + self.part2.as_ref().map_or(Duration::ZERO, |(_, t)| *t)
     }
+}
 
-    pub fn col(&self, j: usize) -> GridColIter<T> {
-	GridColIter { array: &self.array,
-		      dims: self.dims,
-		      front: j,
-		      back: Some(self.dims.1 * (self.dims.0 - 1) + j),
-		      seen: 0
-	}
-    }
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
 }
 
-#[derive(Copy, Clone)]
-pub struct GridRowIter<'a, T> {
-    array: &'a [T],
-    dims: (usize, usize),
-    row: usize,
-    front: usize,
-    back: Option<usize>,
-    seen: usize
+/// The answer to one part of a puzzle. Every variant has a single canonical
+/// display form, which is what the puzzle website expects.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rows of an ASCII-art picture that spells the actual answer.
+    Grid(Vec<String>),
+    /// The part has no puzzle, like part 2 of the last day.
+    NotApplicable,
 }
 
-impl<'a, T: std::fmt::Debug> Iterator for GridRowIter<'a, T> {
-    type Item = &'a T;
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+	matches!(self, Answer::Grid(rows) if rows.len() > 1)
+    }
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-	if self.front == self.dims.1 * (self.row + 1) {
-	    return None
-	} else {
-	    let v = &self.array[self.front];
-	    self.front += 1;
-	    self.seen += 1;
-	    return Some(v)
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+	match self {
+	    Answer::Integer(n) => write!(f, "{:}", n),
+	    Answer::Text(text) => write!(f, "{:}", text),
+	    Answer::Grid(rows) => write!(f, "{:}", rows.join("\n")),
+	    Answer::NotApplicable => write!(f, "n/a"),
 	}
     }
 }
 
-impl<'a, T: std::fmt::Debug> DoubleEndedIterator for GridRowIter<'a, T> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-	if let Some(back) = self.back {
-	    let v = &self.array[back];
-	    self.back = if back > self.dims.1 * self.row {
-		Some(back - 1)
-	    } else {
-		None
-	    };
-	    self.seen += 1;	    
-	    return Some(v)
-	} else {
-	    return None
-	}
-    }		
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {
+	$(impl From<$t> for Answer {
+	    fn from(n: $t) -> Self {
+		Answer::Integer(n as i128)
+	    }
+	})*
+    };
 }
 
-impl<'a, T: std::fmt::Debug> ExactSizeIterator for GridRowIter<'a, T> {
-    fn len(&self) -> usize {
-	self.dims.1 - self.seen
+answer_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+	Answer::Text(text)
     }
 }
 
-#[derive(Copy, Clone)]
-pub struct GridColIter<'a, T> {
-    array: &'a [T],
-    dims: (usize, usize),
-    front: usize,
-    back: Option<usize>,
-    seen: usize
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+	Answer::Text(text.to_string())
+    }
 }
 
-impl<'a, T: std::fmt::Debug> Iterator for  GridColIter<'a, T> {
-    type Item = &'a T;
+pub trait Puzzle {
+    fn load_input(&mut self, input: &str) -> Result<()>;
 
-    fn next(&mut self) -> Option<Self::Item> {
-	if self.front >= self.dims.1 * self.dims.0 {
-	    return None
-	} else {
-	    let v = &self.array[self.front];	    
-	    self.front += self.dims.1;
-	    self.seen += 1;
-	    return Some(v)
-	}
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer>;
+
+    /// Sets a day specific parameter, such as the row day 15 looks at, for
+    /// the next `load_input`. Days without parameters reject every name.
+    fn set_param(&mut self, name: &str, _value: &str) -> std::result::Result<(), String> {
+	Err(format!("unknown parameter {:?}", name))
     }
-}
 
-impl<'a, T: std::fmt::Debug> DoubleEndedIterator for  GridColIter<'a, T> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-	if let Some(back) = self.back {
-	    let v = &self.array[back];	    
-	    self.back = if back >= self.dims.1 {
-		Some(back - self.dims.1)
-	    } else {
-		None
-	    };
-	    self.seen += 1;
-	    return Some(v)
-	} else {
-	    return None
+    /// A picture of how `part` was solved, for days that can draw one. Only
+    /// meaningful once `load_input` has succeeded.
+    fn show(&self, _part: Part) -> Option<String> {
+	None
+    }
+
+    fn run(&mut self, input: &str) -> Result<(Answer, Answer)> {
+	self.load_input(input)?;
+	Ok((self.part1()?, self.part2()?))
+    }
+
+    /// Loads `input` and solves the selected part, or both when `part` is
+    /// `None`, timing every step separately.
+    fn solve(&mut self, input: &str, part: Option<Part>) -> Result<Solution> {
+	let (loaded, load_time) = timed(|| self.load_input(input));
+	loaded?;
+	let mut solution = Solution { load_time, ..Solution::default() };
+	if part != Some(Part::Two) {
+	    let (answer, time) = timed(|| self.part1());
+	    solution.part1 = Some((answer?, time));
+	}
+	if part != Some(Part::One) {
+	    let (answer, time) = timed(|| self.part2());
+	    solution.part2 = Some((answer?, time));
 	}
+	Ok(solution)
     }
 }
 
-impl<'a, T: std::fmt::Debug> ExactSizeIterator for GridColIter<'a, T> {
-    fn len(&self) -> usize {
-	self.dims.0 - self.seen
+/// Loads `input` into `puzzle` and solves only the given part.
+pub fn solve_part<P: Puzzle>(mut puzzle: P, input: &str, part: Part) -> Result<Answer> {
+    puzzle.load_input(input)?;
+    match part {
+	Part::One => puzzle.part1(),
+	Part::Two => puzzle.part2(),
     }
 }
 
-impl<T> Index<usize> for Grid<T> {
-    type Output = [T];
+/// Prints `solution` in the text format of `output::write_day`.
+pub fn print_day(year: usize, day: usize, solution: &Solution)
+{
+    output::write_day(&mut std::io::stdout().lock(), year, day, solution)
+	.expect("failed printing to stdout");
+}
 
-    fn index(&self, row: usize) -> &[T] {
-	let start = row * self.dims.1;
-	&self.array[start..start + self.dims.1]
-    }
+/// Summary statistics over repeated measurements of the same step.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
 }
 
-impl<T> IndexMut<usize> for Grid<T> {
-    fn index_mut(&mut self, row: usize) -> &mut [T] {
-	let start = row * self.dims.1;
-	&mut self.array[start..start + self.dims.1]
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Option<Stats> {
+	if samples.is_empty() {
+	    return None
+	}
+	let mut sorted = samples.to_vec();
+	sorted.sort();
+	let n = sorted.len();
+	let median = if n % 2 == 1 {
+	    sorted[n / 2]
+	} else {
+	    (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+	};
+	let mean = sorted.iter().sum::<Duration>() / n as u32;
+	Some(Stats { min: sorted[0], median, mean })
     }
 }
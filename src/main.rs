@@ -1,111 +1,201 @@
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
+mod bench;
+mod cli;
+mod fetch;
+mod verify;
 
 use aoc_2022_rust as lib;
-use lib::Puzzle;
+use cli::{CliError, Options};
+use lib::output::Writer;
+use lib::{y2022, Puzzle, Registry, Solution};
+use std::io;
+use std::time::Duration;
+use verify::Status;
 
-use day1::Day1;
-use day10::Day10;
-use day11::Day11;
-use day12::Day12;
-use day13::Day13;
-use day14::Day14;
-use day15::Day15;
-use day16::Day16;
-use day17::Day17;
-use day18::Day18;
-use day19::Day19;
-use day2::Day2;
-use day20::Day20;
-use day21::Day21;
-use day22::Day22;
-use day23::Day23;
-use day24::Day24;
-use day25::Day25;
-use day3::Day3;
-use day4::Day4;
-use day5::Day5;
-use day6::Day6;
-use day7::Day7;
-use day8::Day8;
-use day9::Day9;
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    y2022::register(&mut registry);
+    registry
+}
 
-enum Selector {
-    All,
-    Single(usize),
+/// Resolves the day selection of `options` against the implemented days.
+fn select_days(registry: &Registry, options: &Options) -> Result<Vec<usize>, String> {
+    let implemented = registry.days(options.year);
+    if implemented.is_empty() {
+        return Err(CliError::UnsupportedYear(options.year).to_string());
+    }
+    match &options.days {
+        None => Ok(implemented),
+        Some(days) => match days.iter().find(|day| !implemented.contains(day)) {
+            Some(day) => Err(format!(
+                "day {:} of {:} is not implemented",
+                day, options.year
+            )),
+            None => Ok(days.clone()),
+        },
+    }
 }
 
-fn main() {
-    let mut args = std::env::args().skip(1);
+fn read_input(options: &Options, day: usize) -> lib::Result<String> {
+    let path = options.input_path(day);
+    std::fs::read_to_string(&path).map_err(|e| lib::Error::Io {
+        path,
+        message: e.to_string(),
+    })
+}
 
-    let selection = if args.len() == 0 {
-        Selector::All
-    } else {
-        Selector::Single(args.next().unwrap().parse::<usize>().unwrap())
+fn run_day(options: &Options, day: usize, puzzle: &mut dyn Puzzle) -> lib::Result<Solution> {
+    let solution = read_input(options, day).and_then(|input| puzzle.solve(&input, options.part));
+    if let Err(e) = &solution {
+        eprintln!("error: {:}", e);
+    }
+    solution
+}
+
+/// Solves `days` and prints their answers. Returns whether every day was
+/// solved.
+fn run(registry: &Registry, options: &Options, days: &[usize]) -> io::Result<bool> {
+    let mut writer = Writer::new(io::stdout().lock(), options.format)?;
+    let mut solved = true;
+    for &day in days {
+        let mut puzzle = registry.get(options.year, day).unwrap();
+        let solution = run_day(options, day, puzzle.as_mut());
+        writer.write_day(options.year, day, &solution)?;
+        solved &= solution.is_ok();
+        if options.show && solution.is_ok() {
+            for part in options.parts() {
+                if let Some(picture) = puzzle.show(part) {
+                    writer.write_picture(part, &picture)?;
+                }
+            }
+        }
+    }
+    writer.finish()?;
+    Ok(solved)
+}
+
+fn main() {
+    let options = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("error: {:}\n\n{:}", e, cli::USAGE);
+            std::process::exit(2);
+        }
     };
+    if options.help {
+        println!("{:}", cli::USAGE);
+        return;
+    }
+
+    let registry = registry();
+    if options.list {
+        for year in registry.years() {
+            let days: Vec<String> = registry.days(year).iter().map(|d| d.to_string()).collect();
+            println!("{:}: {:}", year, days.join(","));
+        }
+        return;
+    }
 
-    let mut day1 = Day1::new();
-    let mut day2 = Day2::new();
-    let mut day3 = Day3::new();
-    let mut day4 = Day4::new();
-    let mut day5 = Day5::new();
-    let mut day6 = Day6::new();
-    let mut day7 = Day7::new();
-    let mut day8 = Day8::new();
-    let mut day9 = Day9::new();
-    let mut day10 = Day10::new();
-    let mut day11 = Day11::new();
-    let mut day12 = Day12::new();
-    let mut day13 = Day13::new();
-    let mut day14 = Day14::new();
-    let mut day15 = Day15::new();
-    let mut day16 = Day16::new();
-    let mut day17 = Day17::new();
-    let mut day18 = Day18::new();
-    let mut day19 = Day19::new();
-    let mut day20 = Day20::new();
-    let mut day21 = Day21::new();
-    let mut day22 = Day22::new();
-    let mut day23 = Day23::new();
-    let mut day24 = Day24::new();
-    let mut day25 = Day25::new();
+    if options.fetch {
+        let base_url = options
+            .base_url
+            .as_deref()
+            .unwrap_or(fetch::DEFAULT_BASE_URL);
+        let cookie = match fetch::read_cookie(fetch::COOKIE_FILE) {
+            Ok(cookie) => cookie,
+            Err(e) => {
+                eprintln!("error: {:}", e);
+                std::process::exit(2);
+            }
+        };
+        let backend = fetch::backend_for(base_url);
+        let mut fetcher = fetch::Fetcher {
+            backend: backend.as_ref(),
+            base_url,
+            cookie,
+            limiter: fetch::RateLimiter::new(fetch::REQUEST_INTERVAL),
+        };
+        // Inputs are wanted before their solvers exist, so the registry is
+        // not consulted.
+        let days = options.days.clone().unwrap_or_else(|| (1..=25).collect());
+        let mut failed = false;
+        for &day in &days {
+            let status = fetcher.fetch(options.year, day, &options.input_path(day));
+            failed |= matches!(status, fetch::Status::Error(_));
+            println!("day {:02}  {:}", day, status);
+        }
+        if failed {
+            std::process::exit(1);
+        }
+        return;
+    }
 
-    let mut days: Vec<&mut dyn Puzzle> = vec![
-        &mut day1, &mut day2, &mut day3, &mut day4, &mut day5, &mut day6, &mut day7, &mut day8,
-        &mut day9, &mut day10, &mut day11, &mut day12, &mut day13, &mut day14, &mut day15,
-        &mut day16, &mut day17, &mut day18, &mut day19, &mut day20, &mut day21, &mut day22,
-        &mut day23, &mut day24, &mut day25,
-    ];
+    let days = match select_days(&registry, &options) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("error: {:}", e);
+            std::process::exit(2);
+        }
+    };
+    let new_puzzle = |day| registry.get(options.year, day).unwrap();
 
-    match selection {
-        Selector::Single(n) => lib::print_day(2022, n, days[n - 1].run()),
-        Selector::All => {
-            for n in 0..days.len() {
-                lib::print_day(2022, n + 1, days[n].run());
+    if options.verify {
+        let parts = options.parts();
+        let (mut passed, mut failed, mut missing) = (0, 0, 0);
+        for &day in &days {
+            for check in verify::verify_day(day, &parts, || new_puzzle(day)) {
+                match check.status {
+                    Status::Pass => passed += 1,
+                    Status::Missing => missing += 1,
+                    Status::Fail { .. } | Status::Error(_) => failed += 1,
+                }
+                println!("{:}", check);
             }
         }
+        println!();
+        println!(
+            "{:} passed, {:} failed, {:} missing",
+            passed, failed, missing
+        );
+        if failed > 0 {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(runs) = options.bench {
+        let mut total = Duration::ZERO;
+        let mut failed = false;
+        for &day in &days {
+            let bench = read_input(&options, day).and_then(|input| {
+                bench::bench_day(day, &input, options.part, runs, || new_puzzle(day))
+            });
+            match bench {
+                Ok(bench) => {
+                    total += bench.total.median;
+                    println!("{:}", bench);
+                }
+                Err(e) => {
+                    eprintln!("error: {:}", e);
+                    failed = true;
+                }
+            }
+        }
+        println!();
+        println!("total time (sum of medians): {:.2?}", total);
+        if failed {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    match run(&registry, &options, &days) {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(1),
+        // A closed pipe just means the reader has seen enough.
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+        Err(e) => {
+            eprintln!("error: {:}", e);
+            std::process::exit(1);
+        }
     }
 }
@@ -0,0 +1,283 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+use crate::{Answer, Part, Result, Solution};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+	match s {
+	    "text" => Ok(Format::Text),
+	    "json" => Ok(Format::Json),
+	    "csv" => Ok(Format::Csv),
+	    "markdown" | "md" => Ok(Format::Markdown),
+	    _ => Err(format!("unknown output format {:?}", s)),
+	}
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Error(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+	match self {
+	    Status::Ok => write!(f, "ok"),
+	    Status::Error(_) => write!(f, "error"),
+	}
+    }
+}
+
+/// One answer of one day. A day that failed before any part could run is a
+/// single record without part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: usize,
+    pub day: usize,
+    pub part: Option<Part>,
+    pub answer: Option<Answer>,
+    pub time: Option<Duration>,
+    pub status: Status,
+}
+
+impl Record {
+    fn part_number(&self) -> Option<usize> {
+	self.part.map(|part| match part {
+	    Part::One => 1,
+	    Part::Two => 2,
+	})
+    }
+
+    fn error(&self) -> Option<&str> {
+	match &self.status {
+	    Status::Ok => None,
+	    Status::Error(message) => Some(message),
+	}
+    }
+}
+
+/// Flattens the outcome of running a day into one record per part.
+pub fn records(year: usize, day: usize, result: &Result<Solution>) -> Vec<Record> {
+    let record = |part, answer: &Answer, time| Record {
+	year,
+	day,
+	part: Some(part),
+	answer: Some(answer.clone()),
+	time: Some(time),
+	status: Status::Ok,
+    };
+    match result {
+	Ok(solution) => {
+	    let mut records = Vec::new();
+	    if let Some((answer, time)) = &solution.part1 {
+		records.push(record(Part::One, answer, *time));
+	    }
+	    if let Some((answer, time)) = &solution.part2 {
+		records.push(record(Part::Two, answer, *time));
+	    }
+	    records
+	}
+	Err(e) => vec![Record {
+	    year,
+	    day,
+	    part: None,
+	    answer: None,
+	    time: None,
+	    status: Status::Error(e.to_string()),
+	}],
+    }
+}
+
+pub fn write_day(out: &mut impl Write, year: usize, day: usize, solution: &Solution) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "----------- AoC {:04}, Day {:02} -----------", year, day)?;
+    let mut times = vec![format!("load {:.2?}", solution.load_time)];
+    for (n, part) in [(1, &solution.part1), (2, &solution.part2)] {
+	if let Some((answer, time)) = part {
+	    // Pictures start on a line of their own to keep their rows aligned.
+	    let separator = if answer.is_multiline() { "\n" } else { " " };
+	    writeln!(out, "part {:}:{:}{:}", n, separator, answer)?;
+	    times.push(format!("part {:} {:.2?}", n, time));
+	}
+    }
+    writeln!(out, "time: {:}", times.join(", "))
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+	match c {
+	    '"' => escaped.push_str("\\\""),
+	    '\\' => escaped.push_str("\\\\"),
+	    '\n' => escaped.push_str("\\n"),
+	    '\r' => escaped.push_str("\\r"),
+	    '\t' => escaped.push_str("\\t"),
+	    c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+	    c => escaped.push(c),
+	}
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+	Answer::Integer(n) => n.to_string(),
+	Answer::NotApplicable => "null".to_string(),
+	answer => json_string(&answer.to_string()),
+    }
+}
+
+fn json_or_null<T>(value: Option<T>, f: impl FnOnce(T) -> String) -> String {
+    value.map_or_else(|| "null".to_string(), f)
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+	format!("\"{:}\"", s.replace('"', "\"\""))
+    } else {
+	s.to_string()
+    }
+}
+
+fn answer_or_error(record: &Record) -> String {
+    match (&record.answer, record.error()) {
+	(Some(answer), _) => answer.to_string(),
+	(None, Some(error)) => error.to_string(),
+	(None, None) => String::new(),
+    }
+}
+
+fn markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Writes the results of consecutive days in one of the output formats. The
+/// text format prints every day as soon as it is known and a total at the
+/// end; the other formats print one record per part, plus one for a day that
+/// failed.
+pub struct Writer<W: Write> {
+    out: W,
+    format: Format,
+    records: usize,
+    total: Duration,
+    solved: usize,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(mut out: W, format: Format) -> io::Result<Self> {
+	match format {
+	    Format::Text => {}
+	    Format::Json => write!(out, "[")?,
+	    Format::Csv => writeln!(out, "year,day,part,answer,time_ns,status,error")?,
+	    Format::Markdown => {
+		writeln!(out, "| year | day | part | answer | time | status |")?;
+		writeln!(out, "|-----:|----:|-----:|--------|-----:|--------|")?;
+	    }
+	}
+	Ok(Writer { out, format, records: 0, total: Duration::ZERO, solved: 0 })
+    }
+
+    pub fn write_day(&mut self, year: usize, day: usize, result: &Result<Solution>) -> io::Result<()> {
+	if let Ok(solution) = result {
+	    self.total += solution.total_time();
+	    self.solved += 1;
+	}
+	if self.format == Format::Text {
+	    // Errors are reported on stderr by the caller.
+	    return match result {
+		Ok(solution) => write_day(&mut self.out, year, day, solution),
+		Err(_) => Ok(()),
+	    };
+	}
+	for record in records(year, day, result) {
+	    self.write_record(&record)?;
+	}
+	Ok(())
+    }
+
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+	match self.format {
+	    Format::Text => {
+		let part = record.part_number().map_or(String::new(), |n| format!(" part {:}", n));
+		writeln!(self.out, "{:04} day {:02}{:}: {:}", record.year, record.day, part,
+			 answer_or_error(record))?;
+	    }
+	    Format::Json => {
+		let separator = if self.records == 0 { "" } else { "," };
+		write!(self.out, "{:}\n  {{\"year\": {:}, \"day\": {:}, \"part\": {:}, \"answer\": {:}, \"time_ns\": {:}, \"status\": \"{:}\", \"error\": {:}}}",
+		       separator, record.year, record.day,
+		       json_or_null(record.part_number(), |n| n.to_string()),
+		       json_or_null(record.answer.as_ref(), json_answer),
+		       json_or_null(record.time, |t| t.as_nanos().to_string()),
+		       record.status,
+		       json_or_null(record.error(), json_string))?;
+	    }
+	    Format::Csv => {
+		writeln!(self.out, "{:},{:},{:},{:},{:},{:},{:}",
+			 record.year, record.day,
+			 record.part_number().map_or(String::new(), |n| n.to_string()),
+			 csv_field(&record.answer.as_ref().map_or(String::new(), Answer::to_string)),
+			 record.time.map_or(String::new(), |t| t.as_nanos().to_string()),
+			 record.status,
+			 csv_field(record.error().unwrap_or("")))?;
+	    }
+	    Format::Markdown => {
+		let answer = answer_or_error(record);
+		let answer = if answer.contains('\n') {
+		    ["<pre>", &markdown_cell(&answer), "</pre>"].concat()
+		} else {
+		    markdown_cell(&answer)
+		};
+		writeln!(self.out, "| {:} | {:} | {:} | {:} | {:} | {:} |",
+			 record.year, record.day,
+			 record.part_number().map_or(String::new(), |n| n.to_string()),
+			 answer,
+			 record.time.map_or(String::new(), |t| format!("{:.2?}", t)),
+			 record.status)?;
+	    }
+	}
+	self.records += 1;
+	Ok(())
+    }
+
+    /// Prints the picture `puzzle.show(part)` drew, below the answers of its
+    /// day. Only the text format has room for it.
+    pub fn write_picture(&mut self, part: Part, picture: &str) -> io::Result<()> {
+	if self.format != Format::Text {
+	    return Ok(());
+	}
+	let n = match part {
+	    Part::One => 1,
+	    Part::Two => 2,
+	};
+	writeln!(self.out, "part {:} shown:\n{:}", n, picture.trim_end())
+    }
+
+    pub fn finish(mut self) -> io::Result<()> {
+	match self.format {
+	    Format::Text => {
+		writeln!(self.out)?;
+		writeln!(self.out, "total time: {:.2?} ({:} days)", self.total, self.solved)?;
+	    }
+	    Format::Json if self.records == 0 => writeln!(self.out, "]")?,
+	    Format::Json => writeln!(self.out, "\n]")?,
+	    Format::Csv | Format::Markdown => {}
+	}
+	self.out.flush()
+    }
+}
@@ -0,0 +1,74 @@
+use std::str::FromStr;
+use nom::{
+    character::complete::{char, digit1, space0},
+    combinator::{map_res, opt, recognize},
+    multi::separated_list1,
+    sequence::pair,
+    IResult,
+};
+use crate::{parse_complete, Result};
+
+/// A decimal number without a sign. Fails on values that do not fit `T`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// A decimal number with at most one leading `-`. Fails on values that do
+/// not fit `T`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// One or more `item`s separated by commas, each optionally followed by
+/// spaces: `1,2` and `1, 2` both give two items.
+pub fn comma_list<'a, T, P>(item: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, T>
+{
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// Runs `parser` over every line of `input`. The first line that does not
+/// parse completely is reported with its (1-based) line number.
+pub fn parse_lines<'a, T, P>(day: usize, input: &'a str, mut parser: P) -> Result<Vec<T>>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, T>
+{
+    input.lines()
+	.enumerate()
+	.map(|(i, line)| parse_complete(day, i + 1, line, &mut parser))
+	.collect()
+}
+
+/// A run of non-blank lines of an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// The (1-based) line number of the first line.
+    pub line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Block<'a> {
+    /// The lines together with their (1-based) line numbers.
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+	self.lines.iter().enumerate().map(|(i, &line)| (self.line + i, line))
+    }
+}
+
+/// Splits `input` at blank lines. Several blank lines in a row separate
+/// just two blocks; blocks are never empty.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut in_block = false;
+    for (i, line) in input.lines().enumerate() {
+	if line.trim().is_empty() {
+	    in_block = false;
+	} else if in_block {
+	    blocks.last_mut().unwrap().lines.push(line);
+	} else {
+	    blocks.push(Block { line: i + 1, lines: vec![line] });
+	    in_block = true;
+	}
+    }
+    blocks
+}
@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+use crate::Puzzle;
+
+/// Creates a fresh, empty puzzle ready for `load_input`.
+pub type Constructor = fn() -> Box<dyn Puzzle>;
+
+/// All implemented puzzles, keyed by year and day.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    puzzles: BTreeMap<(usize, usize), Constructor>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+	Registry { puzzles: BTreeMap::new() }
+    }
+
+    /// Registers the puzzle of `day` in `year`. Registering the same day
+    /// twice is a programming error and panics.
+    pub fn register(&mut self, year: usize, day: usize, constructor: Constructor) {
+	let previous = self.puzzles.insert((year, day), constructor);
+	assert!(previous.is_none(), "day {:} of {:} is registered twice", day, year);
+    }
+
+    pub fn contains(&self, year: usize, day: usize) -> bool {
+	self.puzzles.contains_key(&(year, day))
+    }
+
+    /// Creates a new puzzle for `day` in `year`, if that day is implemented.
+    pub fn get(&self, year: usize, day: usize) -> Option<Box<dyn Puzzle>> {
+	self.puzzles.get(&(year, day)).map(|constructor| constructor())
+    }
+
+    /// The years with at least one implemented day, in ascending order.
+    pub fn years(&self) -> Vec<usize> {
+	let mut years: Vec<usize> = self.puzzles.keys().map(|&(year, _)| year).collect();
+	years.dedup();
+	years
+    }
+
+    /// The implemented days of `year`, in ascending order.
+    pub fn days(&self, year: usize) -> Vec<usize> {
+	self.puzzles.range((year, 0)..=(year, usize::MAX)).map(|(&(_, day), _)| day).collect()
+    }
+}
@@ -0,0 +1,272 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// What a search found: the distance of every node it reached, the node it
+/// came from and the first goal node it settled, if any.
+///
+/// Searches stop as soon as a goal is settled. The distances of the goal and
+/// of all nodes settled before it are final; nodes still waiting in the
+/// frontier may have a longer distance than their shortest one.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    dist: HashMap<N, u64>,
+    parent: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Eq + Hash + Clone> Search<N> {
+    fn new() -> Self {
+	Search { dist: HashMap::new(), parent: HashMap::new(), goal: None }
+    }
+
+    /// Records `node` at distance `d` unless it is already known to be at
+    /// least as close.
+    fn relax(&mut self, node: &N, d: u64, from: Option<&N>) -> bool {
+	if self.dist.get(node).is_some_and(|&old| old <= d) {
+	    return false;
+	}
+	self.dist.insert(node.clone(), d);
+	if let Some(from) = from {
+	    self.parent.insert(node.clone(), from.clone());
+	}
+	true
+    }
+
+    pub fn distance(&self, node: &N) -> Option<u64> {
+	self.dist.get(node).copied()
+    }
+
+    /// The goal that stopped the search.
+    pub fn goal(&self) -> Option<&N> {
+	self.goal.as_ref()
+    }
+
+    pub fn goal_distance(&self) -> Option<u64> {
+	self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /// Every reached node with its distance, in no particular order.
+    pub fn reached(&self) -> impl Iterator<Item = (&N, u64)> {
+	self.dist.iter().map(|(node, &d)| (node, d))
+    }
+
+    /// The nodes from one of the starts to `to`, both included.
+    pub fn path(&self, to: &N) -> Option<Vec<N>> {
+	self.dist.get(to)?;
+	let mut path = vec![to.clone()];
+	while let Some(prev) = self.parent.get(path.last().unwrap()) {
+	    path.push(prev.clone());
+	}
+	path.reverse();
+	Some(path)
+    }
+
+    /// The path to the goal that stopped the search.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+	self.goal.as_ref().and_then(|goal| self.path(goal))
+    }
+}
+
+/// Breadth first search over unit length edges, starting from all of
+/// `starts` at once. Pass `|_| false` as `goal` to explore everything that
+/// can be reached.
+pub fn bfs<N, I>(starts: impl IntoIterator<Item = N>,
+		 mut neighbours: impl FnMut(&N) -> I,
+		 mut goal: impl FnMut(&N) -> bool) -> Search<N>
+where N: Eq + Hash + Clone,
+      I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+	if search.relax(&start, 0, None) {
+	    queue.push_back(start);
+	}
+    }
+    while let Some(node) = queue.pop_front() {
+	if goal(&node) {
+	    search.goal = Some(node);
+	    break;
+	}
+	let d = search.dist[&node] + 1;
+	for next in neighbours(&node) {
+	    if search.relax(&next, d, Some(&node)) {
+		queue.push_back(next);
+	    }
+	}
+    }
+    search
+}
+
+/// Shortest paths over edges with non-negative costs. `neighbours` yields
+/// each neighbour with the cost of the edge leading to it.
+pub fn dijkstra<N, I>(starts: impl IntoIterator<Item = N>,
+		      neighbours: impl FnMut(&N) -> I,
+		      goal: impl FnMut(&N) -> bool) -> Search<N>
+where N: Eq + Hash + Clone,
+      I: IntoIterator<Item = (N, u64)>,
+{
+    astar(starts, neighbours, |_| 0, goal)
+}
+
+/// Dijkstra's search guided by `heuristic`, which must never overestimate
+/// the remaining cost to a goal for the result to be a shortest path.
+pub fn astar<N, I>(starts: impl IntoIterator<Item = N>,
+		   mut neighbours: impl FnMut(&N) -> I,
+		   mut heuristic: impl FnMut(&N) -> u64,
+		   mut goal: impl FnMut(&N) -> bool) -> Search<N>
+where N: Eq + Hash + Clone,
+      I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search::new();
+    // The heap holds indices into `nodes`, so that `N` need not be `Ord`.
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+	if search.relax(&start, 0, None) {
+	    heap.push(Reverse((heuristic(&start), 0, nodes.len())));
+	    nodes.push(start);
+	}
+    }
+    while let Some(Reverse((_, d, i))) = heap.pop() {
+	let node = nodes[i].clone();
+	// Skip entries that a shorter path has made stale
+	if d > search.dist[&node] {
+	    continue;
+	}
+	if goal(&node) {
+	    search.goal = Some(node);
+	    break;
+	}
+	for (next, cost) in neighbours(&node) {
+	    let next_d = d + cost;
+	    if search.relax(&next, next_d, Some(&node)) {
+		heap.push(Reverse((next_d + heuristic(&next), next_d, nodes.len())));
+		nodes.push(next);
+	    }
+	}
+    }
+    search
+}
+
+/// Distances between all pairs of `nodes`. Pairs without a path are left
+/// out; every node is at distance 0 from itself.
+pub fn floyd_warshall<N, I>(nodes: &[N], mut neighbours: impl FnMut(&N) -> I)
+			    -> HashMap<(N, N), u64>
+where N: Eq + Hash + Clone,
+      I: IntoIterator<Item = (N, u64)>,
+{
+    let n = nodes.len();
+    let index: HashMap<&N, usize> = nodes.iter().enumerate().map(|(i, node)| (node, i)).collect();
+    let mut dist = vec![vec![None; n]; n];
+    for (i, node) in nodes.iter().enumerate() {
+	dist[i][i] = Some(0);
+	for (next, cost) in neighbours(node) {
+	    if let Some(&j) = index.get(&next) {
+		dist[i][j] = Some(dist[i][j].map_or(cost, |d: u64| d.min(cost)));
+	    }
+	}
+    }
+    for k in 0..n {
+	let through = dist[k].clone();
+	for row in dist.iter_mut() {
+	    let Some(ik) = row[k] else { continue };
+	    for (d, kj) in row.iter_mut().zip(&through) {
+		if let Some(kj) = kj {
+		    if d.is_none_or(|d| ik + kj < d) {
+			*d = Some(ik + kj);
+		    }
+		}
+	    }
+	}
+    }
+    let mut result = HashMap::new();
+    for (i, row) in dist.into_iter().enumerate() {
+	for (j, d) in row.into_iter().enumerate() {
+	    if let Some(d) = d {
+		result.insert((nodes[i].clone(), nodes[j].clone()), d);
+	    }
+	}
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -> b costs 5, the detour a -> c -> b costs 2; d is on its own
+    fn weighted(node: &char) -> Vec<(char, u64)> {
+	match node {
+	    'a' => vec![('b', 5), ('c', 1)],
+	    'c' => vec![('b', 1)],
+	    'b' => vec![('e', 3)],
+	    _ => vec![],
+	}
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+	weighted(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn dijkstra_prefers_the_cheaper_detour() {
+	let search = dijkstra(['a'], weighted, |&n| n == 'b');
+	assert_eq!(search.goal(), Some(&'b'));
+	assert_eq!(search.goal_distance(), Some(2));
+	assert_eq!(search.goal_path(), Some(vec!['a', 'c', 'b']));
+    }
+
+    #[test]
+    fn astar_with_admissible_heuristic() {
+	let heuristic = |n: &char| match n {
+	    'a' => 5,
+	    'b' => 3,
+	    'c' => 4,
+	    _ => 0,
+	};
+	let search = astar(['a'], weighted, heuristic, |&n| n == 'e');
+	assert_eq!(search.goal_distance(), Some(5));
+	assert_eq!(search.goal_path(), Some(vec!['a', 'c', 'b', 'e']));
+    }
+
+    #[test]
+    fn bfs_counts_edges() {
+	let search = bfs(['a'], unweighted, |&n| n == 'e');
+	assert_eq!(search.goal_distance(), Some(2));
+	assert_eq!(search.goal_path(), Some(vec!['a', 'b', 'e']));
+    }
+
+    #[test]
+    fn several_starts() {
+	let search = dijkstra(['a', 'c'], weighted, |&n| n == 'e');
+	assert_eq!(search.goal_distance(), Some(4));
+	assert_eq!(search.goal_path(), Some(vec!['c', 'b', 'e']));
+	assert_eq!(search.distance(&'a'), Some(0));
+	assert_eq!(search.path(&'a'), Some(vec!['a']));
+    }
+
+    #[test]
+    fn unreachable_goal() {
+	let search = dijkstra(['a'], weighted, |&n| n == 'd');
+	assert_eq!(search.goal(), None);
+	assert_eq!(search.goal_distance(), None);
+	assert_eq!(search.goal_path(), None);
+	assert_eq!(search.distance(&'e'), Some(5));
+	assert_eq!(search.path(&'d'), None);
+	let search = bfs(['a'], unweighted, |&n| n == 'd');
+	assert_eq!(search.reached().count(), 4);
+    }
+
+    #[test]
+    fn floyd_warshall_distances() {
+	let dist = floyd_warshall(&['a', 'b', 'c', 'd', 'e'], weighted);
+	assert_eq!(dist[&('a', 'b')], 2);
+	assert_eq!(dist[&('a', 'e')], 5);
+	assert_eq!(dist[&('c', 'e')], 4);
+	assert_eq!(dist[&('d', 'd')], 0);
+	assert!(!dist.contains_key(&('b', 'a')));
+	assert!(!dist.contains_key(&('a', 'd')));
+    }
+}
@@ -0,0 +1,231 @@
+use aoc_2022_rust as lib;
+use lib::{Part, Puzzle};
+use std::fmt;
+
+/// Expected answers for one input file, as listed in `inputs/N.answers`.
+///
+/// An answers file has one section per input file of the day:
+///
+/// ```text
+/// [10.test]
+/// part1: 13140
+/// part2:
+/// | ##..##..##..
+/// | ###...###...
+/// ```
+///
+/// Multi-line answers are written as `|`-prefixed lines after an empty value.
+/// Lines like `param: row=10` set a parameter of the puzzle before it loads
+/// the file. Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub file: String,
+    pub params: Vec<(String, String)>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    fn new(file: &str) -> Expected {
+        Expected {
+            file: file.to_string(),
+            params: Vec::new(),
+            part1: None,
+            part2: None,
+        }
+    }
+
+    fn part(&self, part: Part) -> Option<&String> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+pub fn answers_path(day: usize) -> String {
+    format!("inputs/{:}.answers", day)
+}
+
+pub fn parse_answers(text: &str) -> Result<Vec<Expected>, String> {
+    let mut sections: Vec<Expected> = Vec::new();
+    let mut multiline: Option<Part> = None;
+    for (i, line) in text.lines().enumerate() {
+        let error = |msg: &str| format!("line {:}: {:}", i + 1, msg);
+        if let Some(part) = multiline {
+            if let Some(rest) = line.strip_prefix('|') {
+                let section = sections.last_mut().unwrap();
+                let value = match part {
+                    Part::One => section.part1.get_or_insert_with(String::new),
+                    Part::Two => section.part2.get_or_insert_with(String::new),
+                };
+                value.push('\n');
+                value.push_str(rest.strip_prefix(' ').unwrap_or(rest));
+                continue;
+            }
+            multiline = None;
+        }
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(file) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push(Expected::new(file.trim()));
+            continue;
+        }
+        let (key, value) = line
+            .split_once(':')
+            .ok_or_else(|| error("expected `part1: <answer>`"))?;
+        let section = sections
+            .last_mut()
+            .ok_or_else(|| error("answer given before any [file] section"))?;
+        let value = value.trim();
+        let part = match key.trim() {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            "param" => {
+                let (name, value) = value
+                    .split_once('=')
+                    .ok_or_else(|| error("expected `param: <name>=<value>`"))?;
+                section
+                    .params
+                    .push((name.trim().to_string(), value.trim().to_string()));
+                continue;
+            }
+            _ => return Err(error("only part1, part2 and param can be given")),
+        };
+        if value.is_empty() {
+            multiline = Some(part);
+        } else {
+            match part {
+                Part::One => section.part1 = Some(value.to_string()),
+                Part::Two => section.part2 = Some(value.to_string()),
+            }
+        }
+    }
+    Ok(sections)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+    Error(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL  expected {:?}, got {:?}", expected, actual)
+            }
+            Status::Missing => write!(f, "missing"),
+            Status::Error(e) => write!(f, "ERROR {:}", e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: usize,
+    pub file: String,
+    pub part: Option<Part>,
+    pub status: Status,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let part = match self.part {
+            Some(Part::One) => "part 1",
+            Some(Part::Two) => "part 2",
+            None => "",
+        };
+        write!(
+            f,
+            "day {:02}  {:<14} {:<7} {:}",
+            self.day, self.file, part, self.status
+        )
+    }
+}
+
+fn compare(expected: &str, actual: lib::Result<lib::Answer>) -> Status {
+    match actual.map(|answer| answer.to_string()) {
+        Err(e) => Status::Error(e.to_string()),
+        Ok(actual) if expected.trim() == actual.trim() => Status::Pass,
+        Ok(actual) => Status::Fail {
+            expected: expected.trim().to_string(),
+            actual: actual.trim().to_string(),
+        },
+    }
+}
+
+/// Runs a fresh puzzle from `new_puzzle` on every input file listed in the
+/// answers file of `day` and compares the answers of the selected parts.
+pub fn verify_day(
+    day: usize,
+    parts: &[Part],
+    new_puzzle: impl Fn() -> Box<dyn Puzzle>,
+) -> Vec<Check> {
+    let check = |file: &str, part, status| Check {
+        day,
+        file: file.to_string(),
+        part,
+        status,
+    };
+    let path = answers_path(day);
+    let sections = match std::fs::read_to_string(&path) {
+        Ok(text) => parse_answers(&text),
+        Err(_) => return vec![check("-", None, Status::Missing)],
+    };
+    let sections = match sections {
+        Ok(sections) if sections.is_empty() => return vec![check("-", None, Status::Missing)],
+        Ok(sections) => sections,
+        Err(e) => {
+            return vec![check(
+                "-",
+                None,
+                Status::Error(format!("{:}: {:}", path, e)),
+            )]
+        }
+    };
+
+    let mut checks = Vec::new();
+    for expected in sections {
+        let path = format!("inputs/{:}", expected.file);
+        let mut puzzle = new_puzzle();
+        let configured = expected
+            .params
+            .iter()
+            .try_for_each(|(name, value)| puzzle.set_param(name, value));
+        if let Err(e) = configured {
+            checks.push(check(&expected.file, None, Status::Error(e)));
+            continue;
+        }
+        let loaded = std::fs::read_to_string(&path)
+            .map_err(|e| lib::Error::Io {
+                path: path.clone(),
+                message: e.to_string(),
+            })
+            .and_then(|input| puzzle.load_input(&input));
+        if let Err(e) = loaded {
+            checks.push(check(&expected.file, None, Status::Error(e.to_string())));
+            continue;
+        }
+        for &part in parts {
+            let status = match expected.part(part) {
+                None => Status::Missing,
+                Some(answer) => {
+                    let actual = match part {
+                        Part::One => puzzle.part1(),
+                        Part::Two => puzzle.part2(),
+                    };
+                    compare(answer, actual)
+                }
+            };
+            checks.push(check(&expected.file, Some(part), status));
+        }
+    }
+    checks
+}
@@ -0,0 +1,58 @@
+use crate::Registry;
+
+pub const YEAR: usize = 2022;
+
+// Defines the public `solve_part1` and `solve_part2` of a day module, which
+// solve one part of `$puzzle` for some input.
+macro_rules! solvers {
+    ($puzzle:ident, $day:literal) => {
+        #[doc = concat!("Solves part 1 of day ", $day, " for `input`.")]
+        pub fn solve_part1(input: &str) -> $crate::Result<$crate::Answer> {
+            $crate::solve_part($puzzle::new(), input, $crate::Part::One)
+        }
+
+        #[doc = concat!("Solves part 2 of day ", $day, " for `input`.")]
+        pub fn solve_part2(input: &str) -> $crate::Result<$crate::Answer> {
+            $crate::solve_part($puzzle::new(), input, $crate::Part::Two)
+        }
+    };
+}
+
+macro_rules! days {
+    ($($day:literal => $module:ident :: $puzzle:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Adds every solved day of the year to `registry`.
+        pub fn register(registry: &mut Registry) {
+            $(registry.register(YEAR, $day, || Box::new($module::$puzzle::new()));)*
+        }
+    };
+}
+
+days! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21,
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24,
+    25 => day25::Day25,
+}
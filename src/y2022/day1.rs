@@ -0,0 +1,132 @@
+use crate::parse::unsigned;
+use crate::{parse_complete, Answer, Error, Puzzle, Result};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+const DAY: usize = 1;
+
+/// Part 2 asks for the three elves carrying the most calories.
+const TOP: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct Day1 {
+    input: Vec<Elf>,
+}
+
+/// The calories carried by one elf. Elves are numbered from 1 in the order
+/// of the input. Elves compare by calories first.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf {
+    pub calories: usize,
+    pub index: usize,
+}
+
+/// Keeps the `k` largest values pushed into it. Memory stays bounded by `k`
+/// however many values are pushed.
+#[derive(Debug, Clone)]
+pub struct TopK<T: Ord> {
+    k: usize,
+    // A min-heap, so that the smallest kept value is the one to evict
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> TopK<T> {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(value));
+        } else if self.heap.peek().is_some_and(|Reverse(min)| *min < value) {
+            self.heap.pop();
+            self.heap.push(Reverse(value));
+        }
+    }
+
+    /// The kept values, largest first.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(v)| v)
+            .collect()
+    }
+}
+
+/// Reads the calorie list from `reader` one line at a time and returns the
+/// `k` elves that carry the most, largest first.
+pub fn top_elves(reader: impl BufRead, k: usize) -> Result<Vec<Elf>> {
+    let mut top = TopK::new(k);
+    let mut elves = 0;
+    let mut current: Option<Elf> = None;
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| Error::Io {
+            path: format!("day {:} input", DAY),
+            message: e.to_string(),
+        })?;
+        if line.trim().is_empty() {
+            // Several blank lines in a row do not make an elf
+            if let Some(elf) = current.take() {
+                top.push(elf);
+            }
+            continue;
+        }
+        let calories = parse_complete(DAY, i + 1, &line, unsigned::<usize>)?;
+        let elf = current.get_or_insert_with(|| {
+            elves += 1;
+            Elf {
+                calories: 0,
+                index: elves,
+            }
+        });
+        elf.calories += calories;
+    }
+    if let Some(elf) = current {
+        top.push(elf);
+    }
+    Ok(top.into_sorted_vec())
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day1 {
+    pub fn new() -> Day1 {
+        Day1 { input: Vec::new() }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = Vec::new()
+    }
+}
+
+solvers!(Day1, 1);
+
+impl Puzzle for Day1 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        self.input = top_elves(input.as_bytes(), TOP)?;
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let most = self
+            .input
+            .first()
+            .ok_or_else(|| Error::solve(DAY, "no elves in input"))?;
+        Ok(Answer::from(most.calories))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(Answer::from(
+            self.input.iter().map(|elf| elf.calories).sum::<usize>(),
+        ))
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{Answer, Error, Puzzle, Result};
+use nom::error::ErrorKind;
+use std::collections::HashSet;
+
+const DAY: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct Day10 {
+    input: Vec<Instruction>,
+}
+
+impl Default for Day10 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day10 {
+    pub fn new() -> Day10 {
+        Day10 { input: Vec::new() }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = Vec::new()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Instruction {
+    Noop,
+    Addx(isize),
+}
+
+use Instruction::{Addx, Noop};
+
+fn parse(line: &str) -> Option<Instruction> {
+    match line.split_once(' ') {
+        Some(("addx", n)) => n.parse::<isize>().ok().map(Addx),
+        None if line == "noop" => Some(Noop),
+        _ => None,
+    }
+}
+
+fn xvalues(instructions: &Vec<Instruction>) -> Vec<isize> {
+    let mut values = vec![];
+    let mut x = 1isize;
+    for instr in instructions {
+        match instr {
+            Noop => {
+                values.push(x);
+            }
+            Addx(n) => {
+                values.push(x);
+                x += n;
+                values.push(x);
+            }
+        }
+    }
+    values
+}
+
+solvers!(Day10, 10);
+
+impl Puzzle for Day10 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            let instruction =
+                parse(line).ok_or_else(|| Error::parse(DAY, i + 1, line, ErrorKind::Tag))?;
+            self.input.push(instruction);
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let of_interest = [20, 60, 100, 140, 180, 220].iter().collect::<HashSet<_>>();
+        let mut strength = 0;
+        for (i, x) in xvalues(&self.input).iter().enumerate() {
+            if of_interest.contains(&(i + 2)) {
+                strength += *x * ((i + 2) as isize);
+            }
+        }
+        Ok(Answer::from(strength))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut pixels = vec!['#'];
+        for (i, x) in xvalues(&self.input).iter().enumerate() {
+            // Pixel i + 1 is drawn during cycle i + 2, while X is `x`
+            let col = ((i + 1) % 40) as isize;
+            if (col - x).abs() <= 1 {
+                pixels.push('#');
+            } else {
+                pixels.push('.');
+            }
+        }
+
+        if pixels.len() < 240 {
+            return Err(Error::solve(DAY, "the program draws fewer than 240 pixels"));
+        }
+
+        let rows = pixels[..240]
+            .chunks(40)
+            .map(|row| row.iter().collect())
+            .collect();
+        Ok(Answer::Grid(rows))
+    }
+}
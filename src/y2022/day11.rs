@@ -1,20 +1,29 @@
-use aoc_2022_rust::Puzzle;
+use crate::parse::{blocks, comma_list, unsigned};
+use crate::{parse_complete, Answer, Error, Puzzle, Result};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, digit1},
-    combinator::map,
-    multi::many1,
-    sequence::tuple,
+    character::complete::char,
+    combinator::{map, value},
+    error::ErrorKind,
+    sequence::{delimited, preceded},
     IResult,
 };
 use num::integer::lcm;
 
+const DAY: usize = 11;
+
 #[derive(Debug, Clone)]
 pub struct Day11 {
     input: Vec<Monkey>,
 }
 
+impl Default for Day11 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day11 {
     pub fn new() -> Day11 {
         Day11 { input: Vec::new() }
@@ -60,89 +69,94 @@ use Operation::{Add, AddOld, Mul, MulOld};
 
 impl Operation {
     fn parse(input: &str) -> IResult<&str, Operation> {
-        let tp = tuple((
+        preceded(
             tag("  Operation: new = old "),
-            alt((tag("+ "), tag("* "))),
-            alt((tag("old"), digit1)),
-        ));
-        map(tp, |(_, op, rhs)| match op {
-            "+ " => {
-                if rhs == "old" {
-                    AddOld
-                } else {
-                    Add(u64::from_str_radix(rhs, 10).unwrap())
-                }
-            }
-            "* " => {
-                if rhs == "old" {
-                    MulOld
-                } else {
-                    Mul(u64::from_str_radix(rhs, 10).unwrap())
-                }
-            }
-            _ => panic!("unrecognized operation"),
-        })(input)
+            alt((
+                value(AddOld, tag("+ old")),
+                value(MulOld, tag("* old")),
+                map(preceded(tag("+ "), unsigned), Add),
+                map(preceded(tag("* "), unsigned), Mul),
+            )),
+        )(input)
     }
 }
 
 fn parse_id(input: &str) -> IResult<&str, usize> {
-    let id = tuple((tag("Monkey "), digit1, char(':')));
-    map(id, |(_, i, _)| usize::from_str_radix(i, 10).unwrap())(input)
+    delimited(tag("Monkey "), unsigned, char(':'))(input)
 }
 
 fn parse_items(input: &str) -> IResult<&str, Vec<u64>> {
-    let p = tuple((tag("  Starting items: "), many1(alt((digit1, tag(", "))))));
-    map(p, |(_, v)| {
-        v.into_iter()
-            .filter(|&s| s != ", ")
-            .map(|s| u64::from_str_radix(s, 10).unwrap())
-            .collect::<Vec<_>>()
-    })(input)
+    preceded(tag("  Starting items: "), comma_list(unsigned))(input)
 }
 
 fn parse_test(input: &str) -> IResult<&str, u64> {
-    let testp = tuple((tag("  Test: divisible by "), digit1));
-    map(testp, |(_, s)| u64::from_str_radix(s, 10).unwrap())(input)
+    preceded(tag("  Test: divisible by "), unsigned)(input)
 }
 
 fn parse_target(input: &str) -> IResult<&str, usize> {
-    let p = tuple((
+    preceded(
         alt((
             tag("    If true: throw to monkey "),
             tag("    If false: throw to monkey "),
         )),
-        digit1,
-    ));
-    map(p, |(_, s)| usize::from_str_radix(s, 10).unwrap())(input)
+        unsigned,
+    )(input)
 }
 
+solvers!(Day11, 11);
+
 impl Puzzle for Day11 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/11.input");
-        let mut monkey = Monkey::new();
-        for (i, line) in INPUT.lines().enumerate() {
-            match i.rem_euclid(7) {
-                0 => monkey.id = parse_id(line).unwrap().1,
-                1 => monkey.items = parse_items(line).unwrap().1,
-                2 => monkey.operation = Operation::parse(line).unwrap().1,
-                3 => monkey.test = parse_test(line).unwrap().1,
-                4 => monkey.iftrue = parse_target(line).unwrap().1,
-                5 => monkey.iffalse = parse_target(line).unwrap().1,
-                6 => self.input.push(monkey.clone()),
-                _ => panic!("This is not possible!"),
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for block in blocks(input) {
+            let mut monkey = Monkey::new();
+            for (k, (n, line)) in block.numbered().enumerate() {
+                match k {
+                    0 => monkey.id = parse_complete(DAY, n, line, parse_id)?,
+                    1 => monkey.items = parse_complete(DAY, n, line, parse_items)?,
+                    2 => monkey.operation = parse_complete(DAY, n, line, Operation::parse)?,
+                    3 => monkey.test = parse_complete(DAY, n, line, parse_test)?,
+                    4 => monkey.iftrue = parse_complete(DAY, n, line, parse_target)?,
+                    5 => monkey.iffalse = parse_complete(DAY, n, line, parse_target)?,
+                    _ => return Err(Error::parse(DAY, n, line, ErrorKind::Eof)),
+                }
+            }
+            if block.lines.len() < 6 {
+                return Err(Error::solve(
+                    DAY,
+                    format!("the monkey on line {:} is incomplete", block.line),
+                ));
+            }
+            self.input.push(monkey);
+        }
+        if self.input.len() < 2 {
+            return Err(Error::solve(DAY, "at least two monkeys are needed"));
+        }
+        for monkey in &self.input {
+            if monkey.test == 0 {
+                return Err(Error::solve(
+                    DAY,
+                    format!("monkey {:} tests divisibility by 0", monkey.id),
+                ));
+            }
+            if monkey.iftrue >= self.input.len() || monkey.iffalse >= self.input.len() {
+                return Err(Error::solve(
+                    DAY,
+                    format!("monkey {:} throws to a missing monkey", monkey.id),
+                ));
             }
         }
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let monkeys = self.input.clone();
-        simulate(monkeys, 20, None, Some(3))
+        Ok(simulate(monkeys, 20, None, Some(3)))
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Result<Answer> {
         let monkeys = self.input.clone();
         let n = monkeys.iter().fold(1, |acc, m| lcm(acc, m.test));
-        simulate(monkeys, 10000, Some(n), None)
+        Ok(simulate(monkeys, 10000, Some(n), None))
     }
 }
 
@@ -151,7 +165,7 @@ fn simulate(
     steps: usize,
     modulus: Option<u64>,
     reduce: Option<u64>,
-) -> String {
+) -> Answer {
     let mut inspections: Vec<u64> = Vec::with_capacity(monkeys.len());
     for _ in 0..monkeys.len() {
         inspections.push(0)
@@ -161,12 +175,12 @@ fn simulate(
         (monkeys, new_inspections) = round(monkeys, modulus, reduce);
         inspections = inspections
             .iter()
-            .zip(new_inspections.into_iter())
+            .zip(new_inspections)
             .map(|(a, b)| a + b)
             .collect::<Vec<_>>();
     }
     inspections.sort_by(|a, b| b.cmp(a));
-    format!("{:?}", inspections[0] * inspections[1])
+    Answer::from(inspections[0] * inspections[1])
 }
 
 fn update_worry_level(wl: u64, op: Operation, modulus: Option<u64>, reduce: Option<u64>) -> u64 {
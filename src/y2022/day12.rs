@@ -0,0 +1,162 @@
+use crate::grid::Pos;
+use crate::search::bfs;
+use crate::{Answer, Error, Grid, Part, Puzzle, Result};
+use std::cmp::Ordering;
+
+const DAY: usize = 12;
+
+#[derive(Debug, Clone)]
+pub struct Day12 {
+    input: Map,
+    start: Position,
+    goal: Position,
+}
+
+type Map = Grid<char>;
+type Position = Pos;
+
+impl Default for Day12 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day12 {
+    pub fn new() -> Day12 {
+        Day12 {
+            input: Grid::new(),
+            start: (0, 0),
+            goal: (0, 0),
+        }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = Grid::new();
+        self.start = (0, 0);
+        self.goal = (0, 0);
+    }
+}
+
+/// A shortest climb over the heightmap, ending at the goal `E`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub cells: Vec<Position>,
+}
+
+impl Route {
+    pub fn start(&self) -> Position {
+        self.cells[0]
+    }
+
+    pub fn steps(&self) -> usize {
+        self.cells.len() - 1
+    }
+}
+
+fn arrow(from: Position, to: Position) -> char {
+    match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+        (Ordering::Less, _) => '^',
+        (Ordering::Greater, _) => 'v',
+        (_, Ordering::Less) => '<',
+        _ => '>',
+    }
+}
+
+impl Day12 {
+    /// The shortest route to `E`, from `S` for part 1 and from the best
+    /// starting `a` for part 2. `None` when `E` cannot be reached.
+    pub fn route(&self, part: Part) -> Option<Route> {
+        let grid = &self.input;
+        let cells = match part {
+            Part::One => bfs(
+                [self.start],
+                |&p| neighbours_up(grid, p),
+                |&p| p == self.goal,
+            )
+            .goal_path()?,
+            Part::Two => {
+                // Walking down from 'E', the first 'a' reached is the nearest one
+                let mut cells = bfs(
+                    [self.goal],
+                    |&p| neighbours_down(grid, p),
+                    |&p| grid[p] == 'a',
+                )
+                .goal_path()?;
+                cells.reverse();
+                cells
+            }
+        };
+        Some(Route { cells })
+    }
+
+    /// Draws `route` on the heightmap: each cell of the route shows the
+    /// direction of the next step, the goal is `E` and all other cells keep
+    /// their height letter.
+    pub fn render_route(&self, route: &Route) -> String {
+        let mut picture = self.input.clone();
+        for step in route.cells.windows(2) {
+            picture[step[0]] = arrow(step[0], step[1]);
+        }
+        picture[self.goal] = 'E';
+        picture.to_string()
+    }
+}
+
+fn neighbours_up(grid: &Map, pos: Position) -> impl Iterator<Item = Position> + '_ {
+    let height = grid[pos] as u8;
+    grid.neighbours4(pos)
+        .filter(move |&p| grid[p] as u8 <= height + 1)
+}
+
+fn neighbours_down(grid: &Map, pos: Position) -> impl Iterator<Item = Position> + '_ {
+    let height = grid[pos] as u8;
+    grid.neighbours4(pos)
+        .filter(move |&p| grid[p] as u8 + 1 >= height)
+}
+
+solvers!(Day12, 12);
+
+impl Puzzle for Day12 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        let (grid, markers) = Grid::parse_with_markers(input, "SE", |c| match c {
+            'S' => Some('a'),
+            'E' => Some('z'),
+            'a'..='z' => Some(c),
+            _ => None,
+        })
+        .map_err(|e| e.at_day(DAY, input))?;
+        self.start = markers
+            .single('S')
+            .ok_or_else(|| Error::solve(DAY, "the map needs exactly one start position 'S'"))?;
+        self.goal = markers
+            .single('E')
+            .ok_or_else(|| Error::solve(DAY, "the map needs exactly one goal position 'E'"))?;
+        self.input = grid;
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let route = self
+            .route(Part::One)
+            .ok_or_else(|| Error::solve(DAY, "'E' cannot be reached from 'S'"))?;
+        Ok(Answer::from(route.steps()))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let route = self
+            .route(Part::Two)
+            .ok_or_else(|| Error::solve(DAY, "'E' cannot be reached from any 'a'"))?;
+        Ok(Answer::from(route.steps()))
+    }
+
+    fn show(&self, part: Part) -> Option<String> {
+        let route = self.route(part)?;
+        let (row, col) = route.start();
+        Some(format!(
+            "start at row {:}, column {:}\n{:}",
+            row,
+            col,
+            self.render_route(&route)
+        ))
+    }
+}
@@ -1,11 +1,8 @@
-use aoc_2022_rust::Puzzle;
+use crate::parse::unsigned;
+use crate::{parse_complete, Answer, Error, Puzzle, Result};
 use nom::{
-    branch::alt,
-    character::complete::{char, digit1},
-    combinator::map,
-    multi::separated_list0,
-    sequence::delimited,
-    IResult,
+    branch::alt, character::complete::char, combinator::map, multi::separated_list0,
+    sequence::delimited, IResult,
 };
 use std::cmp::{
     Eq, Ordering,
@@ -13,6 +10,8 @@ use std::cmp::{
     PartialEq,
 };
 
+const DAY: usize = 13;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Elem<T> {
     Num(T),
@@ -26,6 +25,12 @@ pub struct Day13 {
     input: Vec<Elem<usize>>,
 }
 
+impl Default for Day13 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day13 {
     pub fn new() -> Day13 {
         Day13 { input: Vec::new() }
@@ -39,7 +44,7 @@ impl Day13 {
 impl<T: Ord + Copy> Ord for Elem<T> {
     fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
-            (&List(ref left), &List(ref right)) => {
+            (List(left), List(right)) => {
                 for (a, b) in left.iter().zip(right.iter()) {
                     if *a < *b {
                         return Less;
@@ -52,11 +57,11 @@ impl<T: Ord + Copy> Ord for Elem<T> {
                 let alen = left.len();
                 let blen = right.len();
                 if alen < blen {
-                    return Less;
+                    Less
                 } else if alen > blen {
-                    return Greater;
+                    Greater
                 } else {
-                    return Equal;
+                    Equal
                 }
             }
 
@@ -76,7 +81,7 @@ impl<T: Ord + Copy> PartialOrd for Elem<T> {
 }
 
 fn num_parser(input: &str) -> IResult<&str, Elem<usize>> {
-    map(digit1, |s| Num(usize::from_str_radix(s, 10).unwrap()))(input)
+    map(unsigned, Num)(input)
 }
 
 fn item_parser(input: &str) -> IResult<&str, Elem<usize>> {
@@ -90,19 +95,25 @@ fn list_parser(input: &str) -> IResult<&str, Elem<usize>> {
             separated_list0(char(','), item_parser),
             char(']'),
         ),
-        |v| List(v),
+        List,
     )(input)
 }
 
+solvers!(Day13, 13);
+
 impl Puzzle for Day13 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/13.input");
-        for line in INPUT.lines().filter(|s| !s.is_empty()) {
-            self.input.push(list_parser(line).unwrap().1);
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate().filter(|(_, s)| !s.is_empty()) {
+            self.input
+                .push(parse_complete(DAY, i + 1, line, list_parser)?);
+        }
+        if !self.input.len().is_multiple_of(2) {
+            return Err(Error::solve(DAY, "the last packet has no pair"));
         }
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let mut lines = self.input.iter();
         let mut i = 1;
         let mut count = 0;
@@ -114,10 +125,10 @@ impl Puzzle for Day13 {
             }
             i += 1;
         }
-        format!("{:?}", count)
+        Ok(Answer::from(count))
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Result<Answer> {
         let mut l = self.input.clone();
         let a = List(vec![List(vec![Num(2)])]);
         let b = List(vec![List(vec![Num(6)])]);
@@ -130,6 +141,6 @@ impl Puzzle for Day13 {
                 key *= i + 1;
             }
         }
-        format!("{:?}", key)
+        Ok(Answer::from(key))
     }
 }
@@ -0,0 +1,169 @@
+use crate::parse::unsigned;
+use crate::{parse_complete, Answer, Error, Point2, Puzzle, Result, SparseGrid};
+use nom::{
+    bytes::complete::tag, character::complete::char, combinator::map, multi::separated_list1,
+    sequence::separated_pair, IResult,
+};
+
+const DAY: usize = 14;
+
+// Keyed by (y, x), so that rows of the rendered map run downwards.
+type Map = SparseGrid<char>;
+
+#[derive(Debug, Clone)]
+pub struct Day14 {
+    input: Map,
+}
+
+type Position = Point2<isize>;
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day14 {
+    pub fn new() -> Day14 {
+        Day14 {
+            input: SparseGrid::new(),
+        }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = SparseGrid::new()
+    }
+}
+
+fn parse_position(input: &str) -> IResult<&str, Position> {
+    map(separated_pair(unsigned, char(','), unsigned), |(x, y)| {
+        Position::new(x, y)
+    })(input)
+}
+
+fn parse_path(input: &str) -> IResult<&str, Vec<Position>> {
+    separated_list1(tag(" -> "), parse_position)(input)
+}
+
+fn get_positions(
+    Position { x: x1, y: y1 }: Position,
+    Position { x: x2, y: y2 }: Position,
+) -> Vec<Position> {
+    let mut path = Vec::new();
+    let (xmin, xmax) = (x1.min(x2), x1.max(x2));
+    let (ymin, ymax) = (y1.min(y2), y1.max(y2));
+    for x in xmin..=xmax {
+        for y in ymin..=ymax {
+            path.push(Position::new(x, y));
+        }
+    }
+    path
+}
+
+const DOWN: Position = Position::new(0, 1);
+const LEFTDOWN: Position = Position::new(-1, 1);
+const RIGHTDOWN: Position = Position::new(1, 1);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Direction {
+    RD,
+    LD,
+    D,
+}
+
+use Direction::{D, LD, RD};
+
+fn next_dir(dir: Direction) -> Option<Direction> {
+    match dir {
+        D => Some(LD),
+        LD => Some(RD),
+        RD => None,
+    }
+}
+
+impl From<Direction> for Position {
+    fn from(val: Direction) -> Self {
+        match val {
+            D => DOWN,
+            LD => LEFTDOWN,
+            RD => RIGHTDOWN,
+        }
+    }
+}
+
+fn step(map: &Map, pos: Position, dir: Option<Direction>) -> Position {
+    if let Some(dir) = dir {
+        let next_pos = pos + dir.into();
+        match map.get(next_pos.to_row_col()) {
+            None => next_pos,
+            Some('#') | Some('o') => step(map, pos, next_dir(dir)),
+            _ => panic!("This is not possible!"),
+        }
+    } else {
+        pos
+    }
+}
+
+fn fall(map: &mut Map, pos: Position, last_rock: isize) -> bool {
+    let mut current_pos = pos;
+    let mut next_pos = step(map, current_pos, Some(D));
+    while next_pos != current_pos {
+        if next_pos.y > last_rock {
+            return false;
+        }
+        current_pos = next_pos;
+        next_pos = step(map, current_pos, Some(D));
+    }
+    map.insert(current_pos.to_row_col(), 'o');
+    true
+}
+
+fn lowest_rock(map: &Map) -> isize {
+    map.bounds().map_or(0, |b| b.max.0)
+}
+
+solvers!(Day14, 14);
+
+impl Puzzle for Day14 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            let positions = parse_complete(DAY, i + 1, line, parse_path)?;
+            for i in 0..positions.len() - 1 {
+                for p in get_positions(positions[i], positions[i + 1]) {
+                    self.input.insert(p.to_row_col(), '#');
+                }
+            }
+        }
+        if self.input.is_empty() {
+            return Err(Error::solve(DAY, "the scan contains no rock"));
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let last_rock = lowest_rock(&self.input);
+        let mut map = self.input.clone();
+        let mut count = 0;
+        let starting_pos = Position::new(500, 0);
+        while fall(&mut map, starting_pos, last_rock) {
+            count += 1;
+        }
+        Ok(Answer::from(count))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let last_rock = lowest_rock(&self.input);
+        let mut map = self.input.clone();
+        for x in -last_rock - 2..=last_rock + 2 {
+            map.insert((last_rock + 2, 500 + x), '#');
+        }
+        let mut count = 0;
+        let starting_pos = Position::new(500, 0);
+        while !map.contains(starting_pos.to_row_col())
+            && fall(&mut map, starting_pos, last_rock + 3)
+        {
+            count += 1;
+        }
+        Ok(Answer::from(count))
+    }
+}
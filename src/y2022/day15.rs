@@ -0,0 +1,143 @@
+use crate::parse::{parse_lines, signed};
+use crate::{Answer, Error, Interval, IntervalSet, Point2, Puzzle, Result};
+use nom::{bytes::complete::tag, combinator::map, sequence::tuple, IResult};
+use std::collections::HashMap;
+
+const DAY: usize = 15;
+
+/// The row of part 1 and the size of the search area of part 2 in the
+/// puzzle input. The example uses 10 and 20 instead.
+const ROW: i32 = 2000000;
+const BOUND: i32 = 4000000;
+
+#[derive(Debug, Clone)]
+pub struct Day15 {
+    input: Scan,
+    row: i32,
+    bound: i32,
+}
+
+type Scan = HashMap<Position, Position>;
+
+type Position = Point2<i32>;
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day15 {
+    pub fn new() -> Day15 {
+        Day15::with_area(ROW, BOUND)
+    }
+
+    /// Counts the positions on `row` in part 1 and looks for the beacon
+    /// within 0..=`bound` on both axes in part 2.
+    pub fn with_area(row: i32, bound: i32) -> Day15 {
+        Day15 {
+            input: HashMap::new(),
+            row,
+            bound,
+        }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = HashMap::new()
+    }
+}
+
+fn parse_position(input: &str) -> IResult<&str, Position> {
+    map(
+        tuple((tag("x="), signed, tag(", y="), signed)),
+        |(_, x, _, y)| Position::new(x, y),
+    )(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, (Position, Position)> {
+    map(
+        tuple((
+            tag("Sensor at "),
+            parse_position,
+            tag(": closest beacon is at "),
+            parse_position,
+        )),
+        |(_, p1, _, p2)| (p1, p2),
+    )(input)
+}
+
+fn radii_of_sensors(scan: &Scan) -> HashMap<Position, i32> {
+    let mut radii = HashMap::new();
+    for (s, b) in scan {
+        radii.insert(*s, s.manhattan(b));
+    }
+    radii
+}
+
+/// The positions on row `y` that some sensor is at least as close to as
+/// to its beacon.
+fn covered_on_line(radii: &HashMap<Position, i32>, y: i32, set: &mut IntervalSet) {
+    set.clear();
+    for (s, d) in radii {
+        let reach = d - (y - s.y).abs();
+        if let Some(interval) = Interval::new((s.x - reach) as i64, (s.x + reach) as i64) {
+            set.insert(interval);
+        }
+    }
+}
+
+solvers!(Day15, 15);
+
+impl Puzzle for Day15 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        self.input = parse_lines(DAY, input, parse_line)?.into_iter().collect();
+        Ok(())
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> std::result::Result<(), String> {
+        let value = value
+            .parse()
+            .map_err(|_| format!("invalid value {:?} for parameter {:?}", value, name))?;
+        match name {
+            "row" => self.row = value,
+            "bound" => self.bound = value,
+            _ => return Err(format!("unknown parameter {:?}", name)),
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let line_y = self.row;
+        let radii = radii_of_sensors(&self.input);
+        let mut covered = IntervalSet::new();
+        covered_on_line(&radii, line_y, &mut covered);
+        // A position holding a beacon can hold a beacon
+        for b in self.input.values().filter(|b| b.y == line_y) {
+            covered.remove(Interval {
+                start: b.x as i64,
+                end: b.x as i64,
+            });
+        }
+        // At most 2^64, so the count fits an integer answer exactly
+        Ok(Answer::Integer(covered.len() as i128))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let radii = radii_of_sensors(&self.input);
+        let area = Interval {
+            start: 0,
+            end: self.bound as i64,
+        };
+        let mut covered = IntervalSet::new();
+        for y in (0..=self.bound).rev() {
+            covered_on_line(&radii, y, &mut covered);
+            if let Some(gap) = covered.gaps(area).first() {
+                return Ok(Answer::from(gap.start * 4000000 + y as i64));
+            }
+        }
+        Err(Error::solve(
+            DAY,
+            "every position in the search area is covered",
+        ))
+    }
+}
@@ -1,14 +1,18 @@
-use aoc_2022_rust::Puzzle;
+use crate::parse::{comma_list, unsigned};
+use crate::search::floyd_warshall;
+use crate::{parse_complete, Answer, Error, Puzzle, Result};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{anychar, digit1},
+    character::complete::anychar,
     combinator::map,
-    multi::separated_list0,
+    error::ErrorKind,
     sequence::{pair, tuple},
     IResult,
 };
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
+
+const DAY: usize = 16;
 
 #[derive(Debug, Clone)]
 pub struct Day16 {
@@ -16,6 +20,12 @@ pub struct Day16 {
     goal: HashSet<Valve>,
 }
 
+impl Default for Day16 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day16 {
     pub fn new() -> Day16 {
         Day16 {
@@ -36,28 +46,24 @@ struct Valve {
     rate: isize,
 }
 
-type Map = HashMap<Valve, Vec<Valve>>;
+type Tunnels = Vec<(char, char)>;
 
 fn parse_valve_name(input: &str) -> IResult<&str, (char, char)> {
     pair(anychar, anychar)(input)
 }
 
-fn parse_decimal(input: &str) -> IResult<&str, isize> {
-    map(digit1, |s: &str| isize::from_str_radix(s, 10).unwrap())(input)
-}
-
-fn parse_valve_name_list(input: &str) -> IResult<&str, Vec<(char, char)>> {
-    separated_list0(tag(", "), parse_valve_name)(input)
+fn parse_valve_name_list(input: &str) -> IResult<&str, Tunnels> {
+    comma_list(parse_valve_name)(input)
 }
 
 // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
 
-fn parse_line(input: &str) -> IResult<&str, (Valve, Vec<(char, char)>)> {
+fn parse_line(input: &str) -> IResult<&str, (Valve, Tunnels)> {
     let p = tuple((
         tag("Valve "),
         parse_valve_name,
         tag(" has flow rate="),
-        parse_decimal,
+        unsigned,
         alt((
             tag("; tunnels lead to valves "),
             tag("; tunnel leads to valve "),
@@ -78,16 +84,16 @@ struct State1 {
 }
 
 fn traverse_alone(dist: &HashMap<(Valve, Valve), isize>, state: State1, max_gain: &mut isize) {
-    if state.remaining_steps <= 2 || state.closed.len() == 0 {
+    if state.remaining_steps <= 2 || state.closed.is_empty() {
         *max_gain = (*max_gain).max(state.gain);
-        return ();
+        return;
     }
     for valve in &state.closed {
         if state.remaining_steps <= dist[&(state.valve, *valve)] + 1 {
             continue;
         }
         let mut new_closed = state.closed.clone();
-        new_closed.remove(&valve);
+        new_closed.remove(valve);
         let new_remaining_steps = state.remaining_steps - dist[&(state.valve, *valve)] - 1;
         let newstate = State1 {
             valve: *valve,
@@ -95,7 +101,7 @@ fn traverse_alone(dist: &HashMap<(Valve, Valve), isize>, state: State1, max_gain
             gain: state.gain + new_remaining_steps * valve.rate,
             remaining_steps: new_remaining_steps,
         };
-        traverse_alone(&dist, newstate, max_gain);
+        traverse_alone(dist, newstate, max_gain);
     }
 }
 
@@ -115,7 +121,7 @@ fn traverse_with_elephant(
     state: State2,
     max_gain: &mut isize,
 ) {
-    if state.remaining_steps <= 2 || state.closed.len() == 0 {
+    if state.remaining_steps <= 2 || state.closed.is_empty() {
         let extra_me = if state.me_traveling < state.remaining_steps {
             (state.remaining_steps - state.me_traveling) * state.me.rate
         } else {
@@ -128,7 +134,7 @@ fn traverse_with_elephant(
         };
 
         *max_gain = (*max_gain).max(state.gain + extra_me + extra_elephant);
-        return ();
+        return;
     }
 
     if state.me_traveling == 0 && state.elephant_traveling == 0 {
@@ -138,8 +144,8 @@ fn traverse_with_elephant(
                     continue;
                 }
                 let mut new_closed = state.closed.clone();
-                new_closed.remove(&new_me);
-                new_closed.remove(&new_elephant);
+                new_closed.remove(new_me);
+                new_closed.remove(new_elephant);
                 let new_remaining_steps_me = state.remaining_steps - dist[&(state.me, *new_me)] - 1;
                 let new_remaining_steps_elephant =
                     state.remaining_steps - dist[&(state.elephant, *new_elephant)] - 1;
@@ -154,13 +160,13 @@ fn traverse_with_elephant(
                     me_traveling: new_remaining_steps - new_remaining_steps_me,
                     elephant_traveling: new_remaining_steps - new_remaining_steps_elephant,
                 };
-                traverse_with_elephant(&dist, newstate, max_gain);
+                traverse_with_elephant(dist, newstate, max_gain);
             }
         }
     } else if state.elephant_traveling == 0 {
         for new_elephant in &state.closed {
             let mut new_closed = state.closed.clone();
-            new_closed.remove(&new_elephant);
+            new_closed.remove(new_elephant);
             let new_remaining_steps_me = state.remaining_steps - state.me_traveling;
             let new_remaining_steps_elephant =
                 state.remaining_steps - dist[&(state.elephant, *new_elephant)] - 1;
@@ -174,12 +180,12 @@ fn traverse_with_elephant(
                 me_traveling: new_remaining_steps - new_remaining_steps_me,
                 elephant_traveling: new_remaining_steps - new_remaining_steps_elephant,
             };
-            traverse_with_elephant(&dist, newstate, max_gain);
+            traverse_with_elephant(dist, newstate, max_gain);
         }
     } else if state.me_traveling == 0 {
         for new_me in &state.closed {
             let mut new_closed = state.closed.clone();
-            new_closed.remove(&new_me);
+            new_closed.remove(new_me);
             let new_remaining_steps_me = state.remaining_steps - dist[&(state.me, *new_me)] - 1;
             let new_remaining_steps_elephant = state.remaining_steps - state.elephant_traveling;
             let new_remaining_steps = new_remaining_steps_me.max(new_remaining_steps_elephant);
@@ -192,55 +198,61 @@ fn traverse_with_elephant(
                 me_traveling: new_remaining_steps - new_remaining_steps_me,
                 elephant_traveling: new_remaining_steps - new_remaining_steps_elephant,
             };
-            traverse_with_elephant(&dist, newstate, max_gain);
+            traverse_with_elephant(dist, newstate, max_gain);
         }
     }
 }
 
+solvers!(Day16, 16);
+
 impl Puzzle for Day16 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/16.input");
+    fn load_input(&mut self, input: &str) -> Result<()> {
         let mut valve_names = HashMap::new();
         let mut valve_list = HashMap::new();
-        let mut input = HashMap::new();
-        for line in INPUT.lines() {
-            let (valve, list) = parse_line(line).unwrap().1;
+        let mut graph = HashMap::new();
+        for (i, line) in input.lines().enumerate() {
+            let (valve, list) = parse_complete(DAY, i + 1, line, parse_line)?;
             valve_names.insert(valve.name, valve);
-            valve_list.insert(valve, list);
+            valve_list.insert(valve, (i + 1, line, list));
         }
         let valves = valve_names.values().collect::<Vec<_>>();
 
         for valve in valves {
-            let list = valve_list[&valve]
+            let (n, line, names) = &valve_list[valve];
+            let list = names
                 .iter()
-                .map(|name| valve_names[name].clone())
-                .collect::<Vec<_>>();
-            input.insert(valve.clone(), list);
+                .map(|name| valve_names.get(name).copied())
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| Error::parse(DAY, *n, line, ErrorKind::Verify))?;
+            graph.insert(*valve, list);
+        }
+        if valve_names.get(&('A', 'A')).map(|v| v.rate) != Some(0) {
+            return Err(Error::solve(
+                DAY,
+                "no valve AA with flow rate 0 to start from",
+            ));
         }
 
-        self.goal = input
+        self.goal = graph
             .keys()
             .filter(|v| v.rate > 0)
-            .map(|v| *v)
+            .copied()
             .collect::<HashSet<_>>();
-        for start in &self.goal {
-            let h = all_shortest_paths(&input, *start, &self.goal);
-            for (end, d) in h {
-                self.dist.insert((*start, end), d);
-            }
-        }
-
-        let starting_valve = Valve {
-            name: ('A', 'A'),
-            rate: 0,
-        };
-        let h = all_shortest_paths(&input, starting_valve, &self.goal);
-        for (end, d) in h {
-            self.dist.insert((starting_valve, end), d);
-        }
+        // Only the distances from AA and from the working valves to the
+        // working valves are ever looked up
+        let valves = graph.keys().copied().collect::<Vec<_>>();
+        let dist = floyd_warshall(&valves, |valve| graph[valve].iter().map(|&v| (v, 1)));
+        self.dist = dist
+            .into_iter()
+            .filter(|((from, to), _)| {
+                (from.name == ('A', 'A') || self.goal.contains(from)) && self.goal.contains(to)
+            })
+            .map(|(pair, d)| (pair, d as isize))
+            .collect();
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let starting_valve = Valve {
             name: ('A', 'A'),
             rate: 0,
@@ -254,10 +266,10 @@ impl Puzzle for Day16 {
         let mut gain = 0;
         traverse_alone(&self.dist, state, &mut gain);
 
-        format!("{:?}", gain)
+        Ok(Answer::from(gain))
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Result<Answer> {
         let starting_valve = Valve {
             name: ('A', 'A'),
             rate: 0,
@@ -269,76 +281,23 @@ impl Puzzle for Day16 {
                 let me = goal_vec[i];
                 let elephant = goal_vec[j];
                 let mut closed = self.goal.clone();
-                closed.remove(&me);
-                closed.remove(&elephant);
+                closed.remove(me);
+                closed.remove(elephant);
                 let remaining_steps_me = 26 - self.dist[&(starting_valve, *me)] - 1;
                 let remaining_steps_elephant = 26 - self.dist[&(starting_valve, *elephant)] - 1;
                 let remaining_steps = remaining_steps_me.max(remaining_steps_elephant);
                 let state = State2 {
                     me: *me,
                     elephant: *elephant,
-                    closed: closed,
+                    closed,
                     gain: 0,
-                    remaining_steps: remaining_steps,
+                    remaining_steps,
                     me_traveling: remaining_steps - remaining_steps_me,
                     elephant_traveling: remaining_steps - remaining_steps_elephant,
                 };
                 traverse_with_elephant(&self.dist, state, &mut gain);
             }
         }
-        format!("{:?}", gain)
-    }
-}
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct Dist {
-    valve: Valve,
-    d: isize,
-}
-
-fn all_shortest_paths(graph: &Map, start: Valve, goal: &HashSet<Valve>) -> HashMap<Valve, isize> {
-    // dist[node] = current shortest distance from `start` to `node`
-    let mut dist: HashMap<Valve, isize> = HashMap::new();
-    for valve in graph.keys() {
-        dist.insert(*valve, isize::MAX);
-    }
-
-    let mut dd: HashMap<Valve, isize> = HashMap::new();
-
-    let mut queue = VecDeque::new();
-
-    // We're at `start`, with a zero cost
-    dist.insert(start, 0);
-    queue.push_back(Dist { valve: start, d: 0 });
-
-    // Examine the frontier with lower cost nodes first (min-heap)
-    while let Some(Dist { valve, d }) = queue.pop_front() {
-        // If reached node is of interest, insert it in the HashMap
-        if goal.contains(&valve) {
-            dd.insert(valve, d);
-        }
-
-        // Important as we may have already found a better way
-        if d > dist[&valve] {
-            continue;
-        }
-
-        // For each node we can reach, see if we can find a way with
-        // a lower cost going through this node
-        for node in &graph[&valve] {
-            let next = Dist {
-                valve: *node,
-                d: d + 1,
-            };
-
-            // If so, add it to the frontier and continue
-            if next.d < dist[&next.valve] {
-                queue.push_back(next);
-                // Relaxation, we have now found a better way
-                dist.insert(next.valve, next.d);
-            }
-        }
+        Ok(Answer::from(gain))
     }
-    // Return HashMap of interesting distances
-    dd
 }
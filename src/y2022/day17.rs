@@ -0,0 +1,236 @@
+use crate::cycle::find_cycle;
+use crate::{Answer, Error, Puzzle, Result, SparseGrid};
+use nom::error::ErrorKind;
+
+const DAY: usize = 17;
+
+#[derive(Debug, Clone)]
+pub struct Day17 {
+    input: String,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day17 {
+    pub fn new() -> Day17 {
+        Day17 {
+            input: String::new(),
+        }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = String::new()
+    }
+}
+
+type Map = SparseGrid<()>;
+
+fn occupied(grid: &Map, (x, y): (usize, usize)) -> bool {
+    grid.contains((x as isize, y as isize))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct State {
+    block: Block,
+    grid: Map,
+    highest: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Block(Vec<(usize, usize)>);
+
+impl Block {
+    fn new(p: Vec<(usize, usize)>) -> Block {
+        Block(p)
+    }
+}
+
+fn move_down(state: State) -> (State, bool) {
+    for (x, y) in &state.block.0 {
+        if *x == 1 || occupied(&state.grid, (x - 1, *y)) {
+            return (state, false);
+        }
+    }
+    let new_block = state
+        .block
+        .0
+        .iter()
+        .map(|(x, y)| (x - 1, *y))
+        .collect::<Vec<_>>();
+
+    (
+        State {
+            block: Block(new_block),
+            grid: state.grid,
+            highest: state.highest,
+        },
+        true,
+    )
+}
+
+fn move_sideways(state: State, dir: char) -> State {
+    match dir {
+        '>' => {
+            for (x, y) in &state.block.0 {
+                if *y == 6 || occupied(&state.grid, (*x, y + 1)) {
+                    return state;
+                }
+            }
+            let new_block = state
+                .block
+                .0
+                .iter()
+                .map(|(x, y)| (*x, y + 1))
+                .collect::<Vec<_>>();
+            State {
+                block: Block(new_block),
+                ..state
+            }
+        }
+        '<' => {
+            for (x, y) in &state.block.0 {
+                if *y == 0 || occupied(&state.grid, (*x, y - 1)) {
+                    return state;
+                }
+            }
+            let new_block = state
+                .block
+                .0
+                .iter()
+                .map(|(x, y)| (*x, y - 1))
+                .collect::<Vec<_>>();
+            State {
+                block: Block(new_block),
+                ..state
+            }
+        }
+        _ => panic!("Not valid direction!"),
+    }
+}
+
+fn initialize_block(block: &Block, highest: usize) -> Block {
+    Block(
+        block
+            .0
+            .iter()
+            .map(|(x, y)| (x + highest + 4, y + 2))
+            .collect(),
+    )
+}
+
+/// The chamber after some rocks came to rest.
+#[derive(Debug, Clone)]
+struct Chamber<'a> {
+    jets: &'a [char],
+    jet_idx: usize,
+    rocks: usize,
+    grid: Map,
+    highest: usize,
+    tops: [usize; 7],
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [char]) -> Chamber<'a> {
+        Chamber {
+            jets,
+            jet_idx: 0,
+            rocks: 0,
+            grid: SparseGrid::new(),
+            highest: 0,
+            tops: [0; 7],
+        }
+    }
+
+    /// Lets the next rock fall until it comes to rest.
+    fn drop_rock(&mut self, blocks: &[Block]) {
+        let mut state = State {
+            block: initialize_block(&blocks[self.rocks % blocks.len()], self.highest),
+            grid: std::mem::take(&mut self.grid),
+            highest: self.highest,
+        };
+        let mut moved = true;
+        while moved {
+            state = move_sideways(state, self.jets[self.jet_idx]);
+            self.jet_idx = (self.jet_idx + 1) % self.jets.len();
+            (state, moved) = move_down(state);
+        }
+
+        // The block came to rest. It is made part of the grid.
+        for &(x, y) in &state.block.0 {
+            state.grid.insert((x as isize, y as isize), ());
+            self.tops[y] = self.tops[y].max(x);
+            self.highest = self.highest.max(x);
+        }
+        self.grid = state.grid;
+        self.rocks += 1;
+    }
+
+    /// What the falling rocks depend on: the next jet, the next rock and
+    /// the shape of the surface.
+    fn fingerprint(&self) -> (usize, usize, [usize; 7]) {
+        let base = self.tops.iter().min().unwrap();
+        (
+            self.jet_idx,
+            self.rocks % 5,
+            self.tops.map(|top| top - base),
+        )
+    }
+}
+
+solvers!(Day17, 17);
+
+impl Puzzle for Day17 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        let jets = input.trim_end();
+        if jets.is_empty() || !jets.chars().all(|c| c == '<' || c == '>') {
+            return Err(Error::parse(DAY, 1, jets, ErrorKind::OneOf));
+        }
+        self.input = jets.to_string();
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let blocks: Vec<Block> = vec![
+            Block::new(vec![(0, 0), (0, 1), (0, 2), (0, 3)]),
+            Block::new(vec![(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]),
+            Block::new(vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]),
+            Block::new(vec![(0, 0), (1, 0), (2, 0), (3, 0)]),
+            Block::new(vec![(0, 0), (0, 1), (1, 0), (1, 1)]),
+        ];
+
+        let jets = self.input.chars().collect::<Vec<_>>();
+        let mut chamber = Chamber::new(&jets);
+        for _ in 0..2022 {
+            chamber.drop_rock(&blocks);
+        }
+        Ok(Answer::from(chamber.highest))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let blocks: Vec<Block> = vec![
+            Block::new(vec![(0, 0), (0, 1), (0, 2), (0, 3)]),
+            Block::new(vec![(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]),
+            Block::new(vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]),
+            Block::new(vec![(0, 0), (1, 0), (2, 0), (3, 0)]),
+            Block::new(vec![(0, 0), (0, 1), (1, 0), (1, 1)]),
+        ];
+
+        let jets = self.input.chars().collect::<Vec<_>>();
+        // The surface must repeat before every pair of jet and rock has
+        // been seen a few times.
+        let limit = 10 * jets.len() * blocks.len();
+        let cycle = find_cycle(
+            Chamber::new(&jets),
+            limit,
+            |chamber| chamber.drop_rock(&blocks),
+            Chamber::fingerprint,
+            |chamber| chamber.highest as i64,
+        )
+        .ok_or_else(|| Error::solve(DAY, "the tower never settles into a cycle"))?;
+        Ok(Answer::from(cycle.extrapolate(1000000000000)))
+    }
+}
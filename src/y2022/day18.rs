@@ -0,0 +1,179 @@
+use crate::search::bfs;
+use crate::{parse_complete, Answer, Point3, Puzzle, Result};
+use nom::{bytes::complete::tag, character::complete, combinator::map, sequence::tuple, IResult};
+use std::collections::HashSet;
+
+const DAY: usize = 18;
+
+type Node = Point3<i32>;
+
+#[derive(Debug, Clone)]
+pub struct Day18 {
+    input: HashSet<Node>,
+    space: Space,
+}
+
+/// The cuboid part 2 explores: the bounding box of the droplet grown by one
+/// on every side, so that the air around the droplet is all connected.
+#[derive(Debug, Copy, Clone, Default)]
+struct Space {
+    min: Node,
+    max: Node,
+}
+
+impl Space {
+    fn around(input: &HashSet<Node>) -> Space {
+        let Some(&first) = input.iter().next() else {
+            return Space::default();
+        };
+        let (mut min, mut max) = (first, first);
+        for node in input {
+            min = Node::new(min.x.min(node.x), min.y.min(node.y), min.z.min(node.z));
+            max = Node::new(max.x.max(node.x), max.y.max(node.y), max.z.max(node.z));
+        }
+        let one = Node::new(1, 1, 1);
+        Space {
+            min: min - one,
+            max: max + one,
+        }
+    }
+
+    fn contains(&self, node: Node) -> bool {
+        (self.min.x..=self.max.x).contains(&node.x)
+            && (self.min.y..=self.max.y).contains(&node.y)
+            && (self.min.z..=self.max.z).contains(&node.z)
+    }
+
+    fn nodes(self) -> impl Iterator<Item = Node> {
+        (self.min.x..=self.max.x).flat_map(move |x| {
+            (self.min.y..=self.max.y)
+                .flat_map(move |y| (self.min.z..=self.max.z).map(move |z| Node::new(x, y, z)))
+        })
+    }
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day18 {
+    pub fn new() -> Day18 {
+        Day18 {
+            input: HashSet::new(),
+            space: Space::default(),
+        }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = HashSet::new();
+        self.space = Space::default();
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, Node> {
+    map(
+        tuple((
+            complete::i32,
+            tag(","),
+            complete::i32,
+            tag(","),
+            complete::i32,
+        )),
+        |(x, _, y, _, z)| Node::new(x, y, z),
+    )(input)
+}
+
+solvers!(Day18, 18);
+
+impl Puzzle for Day18 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            let node = parse_complete(DAY, i + 1, line, parse_line)?;
+            self.input.insert(node);
+        }
+        self.space = Space::around(&self.input);
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(Answer::from(faces(
+            &self.input,
+            &HashSet::new(),
+            &self.space,
+        )))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let empty_space = self
+            .space
+            .nodes()
+            .filter(|node| !self.input.contains(node))
+            .collect::<HashSet<_>>();
+        // A corner of the space is never part of the droplet
+        let outside = connected_component(&self.input, &self.space, self.space.min);
+        let interior = empty_space
+            .difference(&outside)
+            .copied()
+            .collect::<HashSet<_>>();
+
+        Ok(Answer::from(faces(&self.input, &interior, &self.space)))
+    }
+}
+
+const SIDES: [Node; 6] = [
+    Node::new(-1, 0, 0),
+    Node::new(1, 0, 0),
+    Node::new(0, -1, 0),
+    Node::new(0, 1, 0),
+    Node::new(0, 0, -1),
+    Node::new(0, 0, 1),
+];
+
+fn faces(input: &HashSet<Node>, exclude: &HashSet<Node>, space: &Space) -> usize {
+    let mut count = 0;
+    for &node in input {
+        for side in SIDES {
+            let next = node + side;
+            if space.contains(next) && !input.contains(&next) && !exclude.contains(&next) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn neighbours(input: &HashSet<Node>, space: &Space, node: Node) -> Vec<Node> {
+    SIDES
+        .iter()
+        .map(|&side| node + side)
+        .filter(|&next| space.contains(next) && !input.contains(&next))
+        .collect()
+}
+
+#[allow(dead_code)]
+fn all_connected_components(
+    input: &HashSet<Node>,
+    space: &Space,
+    empty_space: &HashSet<Node>,
+) -> Vec<HashSet<Node>> {
+    let mut done = HashSet::new();
+    let mut components = Vec::new();
+
+    for node in empty_space {
+        if !done.contains(node) {
+            let new_component = connected_component(input, space, *node);
+            done = done.union(&new_component).copied().collect();
+            components.push(new_component);
+        }
+    }
+    components
+}
+
+fn connected_component(input: &HashSet<Node>, space: &Space, start: Node) -> HashSet<Node> {
+    bfs([start], |&node| neighbours(input, space, node), |_| false)
+        .reached()
+        .map(|(&node, _)| node)
+        .collect()
+}
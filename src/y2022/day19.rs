@@ -1,11 +1,19 @@
-use aoc_2022_rust::Puzzle;
+use crate::{parse_complete, Answer, Puzzle, Result};
 use nom::{bytes::complete::tag, character::complete, combinator::map, sequence::tuple, IResult};
 
+const DAY: usize = 19;
+
 #[derive(Debug, Clone)]
 pub struct Day19 {
     input: Vec<Blueprint>,
 }
 
+impl Default for Day19 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day19 {
     pub fn new() -> Day19 {
         Day19 { input: Vec::new() }
@@ -108,15 +116,18 @@ fn parse_blueprint(input: &str) -> IResult<&str, Blueprint> {
     )(input)
 }
 
+solvers!(Day19, 19);
+
 impl Puzzle for Day19 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/19.input");
-        for line in INPUT.lines() {
-            self.input.push(parse_blueprint(line).unwrap().1);
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            self.input
+                .push(parse_complete(DAY, i + 1, line, parse_blueprint)?);
         }
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let start = State {
             time: 0,
             robots: (1, 0, 0, 0),
@@ -128,10 +139,10 @@ impl Puzzle for Day19 {
             let geode = dfs(start, *blueprint, 24);
             quality_level += blueprint.i * geode;
         }
-        format!("{:?}", quality_level)
+        Ok(Answer::from(quality_level))
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Result<Answer> {
         let start = State {
             time: 0,
             robots: (1, 0, 0, 0),
@@ -144,7 +155,7 @@ impl Puzzle for Day19 {
             //            println!("{:}", geode);
             total *= geode;
         }
-        format!("{:?}", total)
+        Ok(Answer::from(total))
     }
 }
 
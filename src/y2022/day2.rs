@@ -0,0 +1,257 @@
+use crate::{Answer, Error, Puzzle, Result};
+use nom::error::ErrorKind;
+use std::collections::HashMap;
+
+const DAY: usize = 2;
+
+/// A move of a `Game`, the index of its name.
+pub type Move = usize;
+
+/// How a round ends for the player.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// A rock-paper-scissors style game: a set of moves and which move beats
+/// which. Any two different moves must be related one way.
+#[derive(Debug, Clone)]
+pub struct Game {
+    names: Vec<String>,
+    // beats[a][b] holds when `a` beats `b`
+    beats: Vec<Vec<bool>>,
+    move_scores: Vec<usize>,
+    outcome_scores: [usize; 3],
+}
+
+impl Game {
+    /// A game with the moves `names`, where each pair in `beats` names a
+    /// winning and a losing move. Moves score their position plus 1 and
+    /// outcomes score 0, 3 and 6, as in the puzzle. `None` when `beats`
+    /// names an unknown move, relates a pair both ways or misses a pair.
+    pub fn new(names: &[&str], beats: &[(&str, &str)]) -> Option<Game> {
+        let n = names.len();
+        let find = |name| names.iter().position(|&m| m == name);
+        let mut table = vec![vec![false; n]; n];
+        for &(winner, loser) in beats {
+            let (w, l) = (find(winner)?, find(loser)?);
+            if w == l || table[l][w] {
+                return None;
+            }
+            table[w][l] = true;
+        }
+        let complete = (0..n).all(|a| (0..n).all(|b| a == b || table[a][b] || table[b][a]));
+        complete.then(|| Game {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            beats: table,
+            move_scores: (1..=n).collect(),
+            outcome_scores: [0, 3, 6],
+        })
+    }
+
+    pub fn rock_paper_scissors() -> Game {
+        Game::new(
+            &["Rock", "Paper", "Scissors"],
+            &[
+                ("Rock", "Scissors"),
+                ("Paper", "Rock"),
+                ("Scissors", "Paper"),
+            ],
+        )
+        .unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Game {
+        Game::new(
+            &["Rock", "Paper", "Scissors", "Lizard", "Spock"],
+            &[
+                ("Scissors", "Paper"),
+                ("Paper", "Rock"),
+                ("Rock", "Lizard"),
+                ("Lizard", "Spock"),
+                ("Spock", "Scissors"),
+                ("Scissors", "Lizard"),
+                ("Lizard", "Paper"),
+                ("Paper", "Spock"),
+                ("Spock", "Rock"),
+                ("Rock", "Scissors"),
+            ],
+        )
+        .unwrap()
+    }
+
+    /// Replaces the scores of the moves, in the order of their names, and
+    /// of losing, drawing and winning.
+    pub fn with_scores(mut self, move_scores: Vec<usize>, outcome_scores: [usize; 3]) -> Game {
+        assert_eq!(move_scores.len(), self.names.len());
+        self.move_scores = move_scores;
+        self.outcome_scores = outcome_scores;
+        self
+    }
+
+    pub fn find(&self, name: &str) -> Option<Move> {
+        self.names.iter().position(|m| m == name)
+    }
+
+    pub fn name(&self, m: Move) -> &str {
+        &self.names[m]
+    }
+
+    /// How playing `me` against `you` ends for me.
+    pub fn outcome(&self, me: Move, you: Move) -> Outcome {
+        if self.beats[me][you] {
+            Outcome::Win
+        } else if self.beats[you][me] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// A move that ends with `outcome` against `you`, if there is one.
+    pub fn choose(&self, you: Move, outcome: Outcome) -> Option<Move> {
+        (0..self.names.len()).find(|&me| self.outcome(me, you) == outcome)
+    }
+
+    /// My score for a round where I play `me` against `you`.
+    pub fn score(&self, me: Move, you: Move) -> usize {
+        self.move_scores[me] + self.outcome_scores[self.outcome(me, you).index()]
+    }
+}
+
+/// A round of the strategy guide. The second column is my move in part 1
+/// and the outcome I should aim for in part 2, so it is only read as one
+/// or the other by the part that needs it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Round {
+    line: usize,
+    you: Move,
+    second: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Day2 {
+    game: Game,
+    opponent: HashMap<String, Move>,
+    response: HashMap<String, Move>,
+    outcomes: HashMap<String, Outcome>,
+    input: Vec<Round>,
+}
+
+impl Default for Day2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day2 {
+    pub fn new() -> Day2 {
+        Day2::with_game(
+            Game::rock_paper_scissors(),
+            &["A", "B", "C"],
+            &["X", "Y", "Z"],
+            ["X", "Y", "Z"],
+        )
+        .unwrap()
+    }
+
+    /// Plays `game`, reading the i-th symbol of `opponent` and of `response`
+    /// as its i-th move and the symbols of `outcomes` as losing, drawing
+    /// and winning. `None` when `opponent` or `response` has more symbols
+    /// than `game` has moves.
+    pub fn with_game(
+        game: Game,
+        opponent: &[&str],
+        response: &[&str],
+        outcomes: [&str; 3],
+    ) -> Option<Day2> {
+        let moves = game.names.len();
+        if opponent.len() > moves || response.len() > moves {
+            return None;
+        }
+        let symbols = |list: &[&str]| {
+            list.iter()
+                .enumerate()
+                .map(|(m, s)| (s.to_string(), m))
+                .collect()
+        };
+        Some(Day2 {
+            opponent: symbols(opponent),
+            response: symbols(response),
+            outcomes: [Outcome::Lose, Outcome::Draw, Outcome::Win]
+                .iter()
+                .zip(outcomes)
+                .map(|(&outcome, s)| (s.to_string(), outcome))
+                .collect(),
+            game,
+            input: Vec::new(),
+        })
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = Vec::new()
+    }
+}
+
+solvers!(Day2, 2);
+
+impl Puzzle for Day2 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            let error = || Error::parse(DAY, i + 1, line, ErrorKind::OneOf);
+            let (you, second) = line.split_once(' ').ok_or_else(error)?;
+            self.input.push(Round {
+                line: i + 1,
+                you: *self.opponent.get(you).ok_or_else(error)?,
+                second: second.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut points = 0;
+        for round in &self.input {
+            let me = self.response.get(&round.second).ok_or_else(|| {
+                Error::solve(
+                    DAY,
+                    format!("line {:}: {:?} is not a move", round.line, round.second),
+                )
+            })?;
+            points += self.game.score(*me, round.you);
+        }
+        Ok(Answer::from(points))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut points = 0;
+        for round in &self.input {
+            let outcome = *self.outcomes.get(&round.second).ok_or_else(|| {
+                Error::solve(
+                    DAY,
+                    format!("line {:}: {:?} is not an outcome", round.line, round.second),
+                )
+            })?;
+            let me = self.game.choose(round.you, outcome).ok_or_else(|| {
+                Error::solve(
+                    DAY,
+                    format!(
+                        "no move ends {:?} against {:}",
+                        outcome,
+                        self.game.name(round.you)
+                    ),
+                )
+            })?;
+            points += self.game.score(me, round.you);
+        }
+        Ok(Answer::from(points))
+    }
+}
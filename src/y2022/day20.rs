@@ -1,10 +1,19 @@
-use aoc_2022_rust::Puzzle;
+use crate::{Answer, Error, Puzzle, Result};
+use nom::error::ErrorKind;
+
+const DAY: usize = 20;
 
 #[derive(Debug, Clone)]
 pub struct Day20 {
     input: Vec<Element>,
 }
 
+impl Default for Day20 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day20 {
     pub fn new() -> Day20 {
         Day20 { input: Vec::new() }
@@ -21,18 +30,26 @@ struct Element {
     serial: usize,
 }
 
+solvers!(Day20, 20);
+
 impl Puzzle for Day20 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/20.input");
-        for (i, line) in INPUT.lines().enumerate() {
-            self.input.push(Element {
-                val: line.parse::<i64>().unwrap(),
-                serial: i,
-            });
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            let val = line
+                .parse::<i64>()
+                .map_err(|_| Error::parse(DAY, i + 1, line, ErrorKind::Digit))?;
+            self.input.push(Element { val, serial: i });
+        }
+        if self.input.len() < 2 {
+            return Err(Error::solve(DAY, "at least two numbers are needed to mix"));
+        }
+        if !self.input.iter().any(|e| e.val == 0) {
+            return Err(Error::solve(DAY, "the file contains no 0"));
         }
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let mut list = self.input.clone();
         mix(&mut list);
         let i = list
@@ -42,15 +59,14 @@ impl Puzzle for Day20 {
             .unwrap()
             .0;
 
-        format!(
-            "{:?}",
+        Ok(Answer::from(
             list[(i + 1000) % list.len()].val
                 + list[(i + 2000) % list.len()].val
-                + list[(i + 3000) % list.len()].val
-        )
+                + list[(i + 3000) % list.len()].val,
+        ))
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Result<Answer> {
         let key = 811589153;
         let mut list = self
             .input
@@ -73,12 +89,11 @@ impl Puzzle for Day20 {
             .unwrap()
             .0;
 
-        format!(
-            "{:?}",
+        Ok(Answer::from(
             list[(i + 1000) % list.len()].val
                 + list[(i + 2000) % list.len()].val
-                + list[(i + 3000) % list.len()].val
-        )
+                + list[(i + 3000) % list.len()].val,
+        ))
     }
 }
 
@@ -105,7 +120,7 @@ fn compute_slice(i: usize, v: &mut [Element]) {
     let val = v[i].val % (n as i64 - 1);
 
     if val < 0 {
-        if i as i64 + val - 1 >= 0 {
+        if i as i64 + val > 0 {
             let j = (i as i64 + val) as usize;
             cyclic_shift_right(&mut v[j..i + 1]);
         } else {
@@ -124,8 +139,7 @@ fn compute_slice(i: usize, v: &mut [Element]) {
 }
 
 fn mix(v: &mut [Element]) {
-    let mut current = 0;
-    for _ in 0..v.len() {
+    for current in 0..v.len() {
         let i = v
             .iter()
             .enumerate()
@@ -133,6 +147,5 @@ fn mix(v: &mut [Element]) {
             .unwrap()
             .0;
         compute_slice(i, v);
-        current += 1;
     }
 }
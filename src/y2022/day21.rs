@@ -1,4 +1,4 @@
-use aoc_2022_rust::Puzzle;
+use crate::{parse_complete, Answer, Error, Puzzle, Result};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -10,11 +10,19 @@ use nom::{
 use num::rational::Rational64;
 use std::collections::HashMap;
 
+const DAY: usize = 21;
+
 #[derive(Debug, Clone)]
 pub struct Day21 {
     input: HashMap<String, Instruction>,
 }
 
+impl Default for Day21 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day21 {
     pub fn new() -> Day21 {
         Day21 {
@@ -66,7 +74,7 @@ fn parse_op(input: &str) -> IResult<&str, Instruction> {
 }
 
 fn parse_val(input: &str) -> IResult<&str, Instruction> {
-    map(complete::i64, |s| Val(s))(input)
+    map(complete::i64, Val)(input)
 }
 
 fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
@@ -80,16 +88,33 @@ fn parse_line(input: &str) -> IResult<&str, (String, Instruction)> {
     )(input)
 }
 
+solvers!(Day21, 21);
+
 impl Puzzle for Day21 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/21.input");
-        for line in INPUT.lines() {
-            let t = parse_line(line).unwrap().1;
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            let t = parse_complete(DAY, i + 1, line, parse_line)?;
             self.input.insert(t.0, t.1);
         }
+        if !self.input.contains_key("root") {
+            return Err(Error::solve(DAY, "there is no monkey named root"));
+        }
+        for (name, instr) in &self.input {
+            if let Op(_, a, b) = instr {
+                for operand in [a, b] {
+                    if !self.input.contains_key(operand) {
+                        return Err(Error::solve(
+                            DAY,
+                            format!("{:} waits for unknown monkey {:}", name, operand),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let mut vals = self
             .input
             .iter()
@@ -98,17 +123,27 @@ impl Puzzle for Day21 {
                 _ => None,
             })
             .collect::<HashMap<String, i64>>();
-        format!("{:?}", eval(&mut vals, &self.input, "root"))
+        Ok(Answer::from(eval(&mut vals, &self.input, "root")))
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Result<Answer> {
         let mut vals = HashMap::new();
         if let Op(_, ref left, ref right) = self.input["root"] {
             let Expr { a: a1, b: b1 } = compute_expr(&mut vals, &self.input, left);
             let Expr { a: a2, b: b2 } = compute_expr(&mut vals, &self.input, right);
-            format!("{:?}", ((b2 - b1) / (a1 - a2)).numer())
+            if a1 == a2 {
+                return Err(Error::solve(
+                    DAY,
+                    "both sides of root change alike with humn",
+                ));
+            }
+            let humn = (b2 - b1) / (a1 - a2);
+            if !humn.is_integer() {
+                return Err(Error::solve(DAY, "humn would have to yell a fraction"));
+            }
+            Ok(Answer::from(*humn.numer()))
         } else {
-            panic!("root is not what expected!");
+            Err(Error::solve(DAY, "root does not compare two monkeys"))
         }
     }
 }
@@ -135,7 +170,7 @@ fn compute_expr(
         return vals[name];
     }
     if vals.contains_key(name) {
-        return vals[name];
+        vals[name]
     } else {
         match input[name] {
             Val(n) => {
@@ -153,7 +188,7 @@ fn compute_expr(
                 vals.insert(name.to_string(), compute(e1, e2, op));
             }
         }
-        return vals[name];
+        vals[name]
     }
 }
 
@@ -192,7 +227,7 @@ fn compute(Expr { a: a1, b: b1 }: Expr, Expr { a: a2, b: b2 }: Expr, op: Operato
 
 fn eval(vals: &mut HashMap<String, i64>, input: &HashMap<String, Instruction>, name: &str) -> i64 {
     if vals.contains_key(name) {
-        return vals[name];
+        vals[name]
     } else {
         match input[name] {
             Op(op, ref a, ref b) => {
@@ -201,13 +236,13 @@ fn eval(vals: &mut HashMap<String, i64>, input: &HashMap<String, Instruction>, n
                 let u = eval(vals, input, b);
                 vals.insert(b.clone(), u);
                 match op {
-                    Add => return v + u,
-                    Sub => return v - u,
-                    Mul => return v * u,
-                    Div => return v / u,
+                    Add => v + u,
+                    Sub => v - u,
+                    Mul => v * u,
+                    Div => v / u,
                 }
             }
-            Val(n) => return n,
+            Val(n) => n,
         }
     }
 }
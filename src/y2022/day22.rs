@@ -0,0 +1,386 @@
+use crate::Direction4::{self, Down, Left, Right, Up};
+use crate::{parse_complete, Answer, Error, Grid, Point3, Puzzle, Result};
+use nom::{
+    branch::alt,
+    character::complete::{self, char},
+    combinator::map,
+    multi::many1,
+    sequence::pair,
+    IResult,
+};
+use std::collections::{hash_map::Entry, HashMap};
+
+const DAY: usize = 22;
+
+type Map = Grid<char>;
+
+#[derive(Debug, Clone)]
+pub struct Day22 {
+    instructions: Vec<Instruction>,
+    // Side length of a face of the cube
+    n: isize,
+    faces: Vec<Face>,
+    flat: Gluing,
+    cube: Gluing,
+}
+
+/// One face of the cube: its tiles and the map cell of its top left tile.
+#[derive(Debug, Clone)]
+struct Face {
+    origin: (usize, usize),
+    tiles: Map,
+}
+
+/// Where stepping off a face in a direction leads: the face and the
+/// direction faced on it.
+type Gluing = HashMap<(usize, Direction4), (usize, Direction4)>;
+
+impl Default for Day22 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day22 {
+    pub fn new() -> Day22 {
+        Day22 {
+            instructions: Vec::new(),
+            n: 0,
+            faces: Vec::new(),
+            flat: HashMap::new(),
+            cube: HashMap::new(),
+        }
+    }
+
+    pub fn _clear(&mut self) {
+        self.instructions = Vec::new();
+        self.n = 0;
+        self.faces = Vec::new();
+        self.flat = HashMap::new();
+        self.cube = HashMap::new();
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Instruction {
+    steps: u32,
+    turn: char,
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Instruction> {
+    map(
+        pair(alt((char('R'), char('L'))), complete::u32),
+        |(turn, steps)| Instruction { steps, turn },
+    )(input)
+}
+
+fn parse_directions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    many1(parse_direction)(input)
+}
+
+solvers!(Day22, 22);
+
+impl Puzzle for Day22 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        let layout_error = || Error::solve(DAY, "the map is not the net of a cube");
+        let (map, path) = input.split_once("\n\n").ok_or_else(layout_error)?;
+        let tiles = map.chars().filter(|c| !c.is_whitespace()).count();
+        let n = (1..).find(|n| 6 * n * n >= tiles).unwrap_or(0);
+        if n == 0 || 6 * n * n != tiles {
+            return Err(layout_error());
+        }
+        // Lines end after their last tile, so pad them to whole faces.
+        let width = map
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0)
+            .next_multiple_of(n);
+        let rows = map
+            .lines()
+            .map(|line| {
+                let mut row: Vec<char> = line.chars().collect();
+                row.resize(width, ' ');
+                row
+            })
+            .collect();
+        let map = Grid::from_rows(rows).map_err(|e| e.at_day(DAY, input))?;
+        if !map.dims.0.is_multiple_of(n) {
+            return Err(layout_error());
+        }
+
+        // Faces in reading order, so the first one holds the start.
+        let blocks = (map.dims.0 / n, map.dims.1 / n);
+        let mut faces = Vec::new();
+        let mut index = HashMap::new();
+        for r in 0..blocks.0 {
+            for c in 0..blocks.1 {
+                let tiles = map.window(r * n..(r + 1) * n, c * n..(c + 1) * n);
+                if tiles.array.iter().all(|&t| t == ' ') {
+                    continue;
+                }
+                if tiles.array.iter().any(|&t| t != '.' && t != '#') {
+                    return Err(layout_error());
+                }
+                index.insert((r, c), faces.len());
+                faces.push(Face {
+                    origin: (r * n, c * n),
+                    tiles,
+                });
+            }
+        }
+        if faces.len() != 6 {
+            return Err(layout_error());
+        }
+        self.flat = wrap_flat(blocks, &index);
+        self.cube = fold_cube(&index).ok_or_else(layout_error)?;
+        self.n = n as isize;
+        self.faces = faces;
+
+        let line = map.dims.0 + 2;
+        self.instructions = parse_complete(DAY, line, path.trim_end(), parse_directions)?;
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        self.password(&self.flat)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        self.password(&self.cube)
+    }
+}
+
+impl Day22 {
+    /// Follows the path over the faces joined by `gluing`.
+    fn password(&self, gluing: &Gluing) -> Result<Answer> {
+        let start = self.faces[0].tiles[0]
+            .iter()
+            .position(|&t| t == '.')
+            .ok_or_else(|| Error::solve(DAY, "the top row has no open tile"))?;
+        // The path starts with a turn, so face up before it.
+        let mut state = State::new(0, (0, start as isize), Up);
+        for instruction in &self.instructions {
+            state = self.exec(gluing, state, *instruction);
+        }
+        let (r, c) = self.faces[state.face].origin;
+        let (i, j) = (r as isize + state.pos.0, c as isize + state.pos.1);
+        Ok(Answer::from(
+            1000 * (i + 1) + 4 * (j + 1) + value(state.dir),
+        ))
+    }
+
+    fn exec(&self, gluing: &Gluing, mut state: State, instruction: Instruction) -> State {
+        state.dir = match instruction.turn {
+            'R' => state.dir.turn_right(),
+            'L' => state.dir.turn_left(),
+            _ => panic!("Unrecognized turn!"),
+        };
+        for _ in 0..instruction.steps {
+            let new_state = self.next(gluing, state);
+            if new_state == state {
+                break;
+            }
+            state = new_state
+        }
+        state
+    }
+
+    /// One step ahead, or `state` itself when a wall is in the way.
+    fn next(&self, gluing: &Gluing, state: State) -> State {
+        let n = self.n;
+        let State {
+            face,
+            pos: (i, j),
+            dir,
+        } = state;
+        let (di, dj) = dir.offset();
+        let (ni, nj) = (i + di, j + dj);
+        let ahead = if (0..n).contains(&ni) && (0..n).contains(&nj) {
+            State::new(face, (ni, nj), dir)
+        } else {
+            let (new_face, new_dir) = gluing[&(face, dir)];
+            // Seen from outside, the two faces run along their common edge
+            // in opposite directions.
+            let k = n - 1 - edge_index(n, (i, j), dir);
+            State::new(new_face, edge_position(n, k, new_dir.reverse()), new_dir)
+        };
+        let (i, j) = ahead.pos;
+        if self.faces[ahead.face].tiles[(i as usize, j as usize)] == '#' {
+            state
+        } else {
+            ahead
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct State {
+    face: usize,
+    pos: (isize, isize),
+    dir: Direction4,
+}
+
+impl State {
+    fn new(face: usize, pos: (isize, isize), dir: Direction4) -> State {
+        State { face, pos, dir }
+    }
+}
+
+fn value(dir: Direction4) -> isize {
+    match dir {
+        Up => 3,
+        Down => 1,
+        Right => 0,
+        Left => 2,
+    }
+}
+
+/// How far along the `edge` of a face `pos` is, going clockwise around the
+/// face.
+fn edge_index(n: isize, (i, j): (isize, isize), edge: Direction4) -> isize {
+    match edge {
+        Up => j,
+        Right => i,
+        Down => n - 1 - j,
+        Left => n - 1 - i,
+    }
+}
+
+/// The tile `k` steps clockwise along the `edge` of a face.
+fn edge_position(n: isize, k: isize, edge: Direction4) -> (isize, isize) {
+    match edge {
+        Up => (0, k),
+        Right => (k, n - 1),
+        Down => (n - 1, n - 1 - k),
+        Left => (n - 1 - k, 0),
+    }
+}
+
+/// Part 1 joins the faces of the map as a flat torus: stepping off a face
+/// continues with the next face in the same direction, wrapping around the
+/// map.
+fn wrap_flat(blocks: (usize, usize), index: &HashMap<(usize, usize), usize>) -> Gluing {
+    let mut gluing = HashMap::new();
+    for (&(r, c), &face) in index {
+        for dir in Direction4::ALL {
+            let (dr, dc) = dir.offset();
+            let (mut r, mut c) = (r as isize, c as isize);
+            let next = loop {
+                r = (r + dr).rem_euclid(blocks.0 as isize);
+                c = (c + dc).rem_euclid(blocks.1 as isize);
+                if let Some(&next) = index.get(&(r as usize, c as usize)) {
+                    break next;
+                }
+            };
+            gluing.insert((face, dir), (next, dir));
+        }
+    }
+    gluing
+}
+
+type Vector = Point3<i32>;
+
+/// How a face lies on the cube: its outward normal and the directions in
+/// space of its columns (`right`) and rows (`down`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Orientation {
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Orientation {
+    /// The direction in space in which the `edge` of the face lies.
+    fn outward(&self, edge: Direction4) -> Vector {
+        match edge {
+            Up => -self.down,
+            Down => self.down,
+            Right => self.right,
+            Left => -self.right,
+        }
+    }
+
+    /// The orientation of the face next to this one across `edge`, once
+    /// the net is folded.
+    fn roll(self, edge: Direction4) -> Orientation {
+        let Orientation {
+            normal,
+            right,
+            down,
+        } = self;
+        match edge {
+            Up => Orientation {
+                normal: -down,
+                down: normal,
+                right,
+            },
+            Down => Orientation {
+                normal: down,
+                down: -normal,
+                right,
+            },
+            Right => Orientation {
+                normal: right,
+                right: -normal,
+                down,
+            },
+            Left => Orientation {
+                normal: -right,
+                right: normal,
+                down,
+            },
+        }
+    }
+}
+
+/// Part 2 folds the net into a cube. Faces are placed in space by rolling
+/// from one to the next across the net; two faces are then glued wherever
+/// one lies beyond an edge of the other. `None` when the net does not fold
+/// into a cube.
+fn fold_cube(index: &HashMap<(usize, usize), usize>) -> Option<Gluing> {
+    let first = index.iter().min_by_key(|&(_, &face)| face)?;
+    let mut placed = HashMap::new();
+    placed.insert(
+        *first.1,
+        Orientation {
+            normal: Vector::new(0, 0, 1),
+            right: Vector::new(1, 0, 0),
+            down: Vector::new(0, 1, 0),
+        },
+    );
+    let mut todo = vec![*first.0];
+    while let Some((r, c)) = todo.pop() {
+        let orientation = placed[&index[&(r, c)]];
+        for dir in Direction4::ALL {
+            let (dr, dc) = dir.offset();
+            let block = ((r as isize + dr) as usize, (c as isize + dc) as usize);
+            if let Some(&next) = index.get(&block) {
+                if let Entry::Vacant(slot) = placed.entry(next) {
+                    slot.insert(orientation.roll(dir));
+                    todo.push(block);
+                }
+            }
+        }
+    }
+    let by_normal: HashMap<Vector, usize> = placed
+        .iter()
+        .map(|(&face, orientation)| (orientation.normal, face))
+        .collect();
+    if placed.len() != index.len() || by_normal.len() != 6 {
+        return None;
+    }
+
+    let mut gluing = HashMap::new();
+    for (&face, orientation) in &placed {
+        for dir in Direction4::ALL {
+            let next = by_normal[&orientation.outward(dir)];
+            // The edge of `next` that lies towards this face
+            let edge = Direction4::ALL
+                .into_iter()
+                .find(|&edge| placed[&next].outward(edge) == orientation.normal)?;
+            gluing.insert((face, dir), (next, edge.reverse()));
+        }
+    }
+    Some(gluing)
+}
@@ -1,5 +1,7 @@
-use aoc_2022_rust::Puzzle;
-use std::collections::{HashMap, HashSet};
+use crate::{Answer, Error, Grid, Puzzle, Result, SparseGrid};
+use std::collections::HashMap;
+
+const DAY: usize = 23;
 
 #[derive(Debug, Clone)]
 pub struct Day23 {
@@ -10,33 +12,47 @@ type Direction = (isize, isize);
 
 type Position = (isize, isize);
 
-type Map = HashSet<Position>;
+type Map = SparseGrid<()>;
+
+impl Default for Day23 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Day23 {
     pub fn new() -> Day23 {
         Day23 {
-            input: HashSet::new(),
+            input: SparseGrid::new(),
         }
     }
 
     pub fn _clear(&mut self) {
-        self.input = HashSet::new();
+        self.input = SparseGrid::new();
     }
 }
 
+solvers!(Day23, 23);
+
 impl Puzzle for Day23 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/23.input");
-        for (i, line) in INPUT.lines().enumerate() {
-            for (j, c) in line.chars().enumerate() {
-                if c == '#' {
-                    self.input.insert((i as isize, j as isize));
-                }
-            }
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        let grid = Grid::parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .map_err(|e| e.at_day(DAY, input))?;
+        self.input = SparseGrid::from_grid(&grid, (0, 0), |&elf| elf)
+            .positions()
+            .map(|pos| (pos, ()))
+            .collect();
+        if self.input.is_empty() {
+            return Err(Error::solve(DAY, "there are no elves in the grove"));
         }
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let mut map = self.input.clone();
         let mut idx = 0;
 
@@ -45,18 +61,12 @@ impl Puzzle for Day23 {
             idx = (idx + 1) % 4;
         }
 
-        let max_i = map.iter().max_by_key(|(i, _)| i).unwrap().0;
-        let min_i = map.iter().min_by_key(|(i, _)| i).unwrap().0;
-        let max_j = map.iter().max_by_key(|(_, j)| j).unwrap().1;
-        let min_j = map.iter().min_by_key(|(_, j)| j).unwrap().1;
+        let empty_cells = map.bounds().map_or(0, |b| b.area()) - map.len();
 
-        let empty_cells = (max_i - min_i + 1) * (max_j - min_j + 1) - map.len() as isize;
-
-        //print_map(&map);
-        format!("{:?}", empty_cells)
+        Ok(Answer::from(empty_cells))
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Result<Answer> {
         let mut map = self.input.clone();
         let mut new_map;
         let mut idx = 0;
@@ -72,7 +82,7 @@ impl Puzzle for Day23 {
             idx = (idx + 1) % 4;
         }
 
-        format!("{:?}", k)
+        Ok(Answer::from(k))
     }
 }
 
@@ -80,35 +90,31 @@ const N: Direction = (-1, 0);
 const S: Direction = (1, 0);
 const W: Direction = (0, -1);
 const E: Direction = (0, 1);
-const NW: Direction = (-1, -1);
-const NE: Direction = (-1, 1);
-const SW: Direction = (1, -1);
-const SE: Direction = (1, 1);
 
 const DIR: &[Direction] = &[N, S, W, E];
 
 fn round(map: &Map, idx: usize) -> (Map, bool) {
     let mut proposed_map: HashMap<Position, Vec<Position>> = HashMap::new();
-    for pos in map {
-        let new_pos = proposed_position(&map, *pos, idx);
+    for pos in map.positions() {
+        let new_pos = proposed_position(map, pos, idx);
         if let Some(x) = proposed_map.get_mut(&new_pos) {
-            x.push(*pos);
+            x.push(pos);
         } else {
-            proposed_map.insert(new_pos, vec![*pos]);
+            proposed_map.insert(new_pos, vec![pos]);
         }
     }
 
-    let mut new_map = HashSet::new();
+    let mut new_map = SparseGrid::new();
     let mut moved = false;
     for (new_pos, prev_positions) in proposed_map {
         if prev_positions.len() == 1 {
-            new_map.insert(new_pos);
+            new_map.insert(new_pos, ());
             if new_pos != prev_positions[0] {
                 moved = true;
             }
         } else {
             for pos in prev_positions {
-                new_map.insert(pos);
+                new_map.insert(pos, ());
             }
         }
     }
@@ -130,14 +136,11 @@ fn check_dir(map: &Map, (i, j): Position, dir: Direction) -> bool {
             (new_pos.0 + 1, new_pos.1),
         ]
     };
-    positions.iter().all(|pos| !map.contains(pos))
+    positions.iter().all(|&pos| !map.contains(pos))
 }
 
 fn proposed_position(map: &Map, (i, j): Position, idx: usize) -> Position {
-    let empty_spaces = [N, S, W, E, NW, NE, SW, SE]
-        .iter()
-        .all(|(x, y)| !map.contains(&(i + x, j + y)));
-    if empty_spaces {
+    if map.neighbours8((i, j)).next().is_none() {
         return (i, j);
     }
     for k in 0..4 {
@@ -148,22 +151,3 @@ fn proposed_position(map: &Map, (i, j): Position, idx: usize) -> Position {
     }
     (i, j)
 }
-
-#[allow(dead_code)]
-fn print_map(map: &Map) {
-    let max_i = map.iter().max_by_key(|(i, _)| i).unwrap().0;
-    let min_i = map.iter().min_by_key(|(i, _)| i).unwrap().0;
-    let max_j = map.iter().max_by_key(|(_, j)| j).unwrap().1;
-    let min_j = map.iter().min_by_key(|(_, j)| j).unwrap().1;
-
-    for i in min_i..=max_i {
-        for j in min_j..=max_j {
-            if map.contains(&(i, j)) {
-                print!("#");
-            } else {
-                print!(".");
-            }
-        }
-        println!();
-    }
-}
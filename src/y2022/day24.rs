@@ -1,5 +1,8 @@
-use aoc_2022_rust::Puzzle;
-use std::collections::{HashMap, HashSet, VecDeque};
+use crate::search;
+use crate::{Answer, Error, Grid, Puzzle, Result};
+use std::collections::{HashMap, HashSet};
+
+const DAY: usize = 24;
 
 #[derive(Debug, Clone)]
 pub struct Day24 {
@@ -38,6 +41,12 @@ impl Bliz {
     }
 }
 
+impl Default for Day24 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day24 {
     pub fn new() -> Day24 {
         Day24 {
@@ -52,55 +61,67 @@ impl Day24 {
     }
 }
 
+solvers!(Day24, 24);
+
 impl Puzzle for Day24 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/24.input");
-        let rows = (INPUT.lines().count() - 2) as isize;
-        let cols = (INPUT.lines().take(1).next().unwrap().chars().count() - 2) as isize;
-        let mut input = HashMap::new();
-        input.insert(R, HashSet::new());
-        input.insert(L, HashSet::new());
-        input.insert(U, HashSet::new());
-        input.insert(D, HashSet::new());
-
-        for (i, line) in INPUT.lines().enumerate() {
-            for (j, c) in line.chars().enumerate() {
-                if let Some(x) = input.get_mut(&Bliz::from_char(c)) {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        let grid = Grid::parse(input, |c| "#.<>^v".contains(c).then_some(c))
+            .map_err(|e| e.at_day(DAY, input))?;
+        let (rows, cols) = grid.dims;
+        if rows < 3 || cols < 3 {
+            return Err(Error::solve(DAY, "the valley is too small"));
+        }
+        let (rows, cols) = ((rows - 2) as isize, (cols - 2) as isize);
+        let mut blizzards = HashMap::new();
+        blizzards.insert(R, HashSet::new());
+        blizzards.insert(L, HashSet::new());
+        blizzards.insert(U, HashSet::new());
+        blizzards.insert(D, HashSet::new());
+
+        for i in 0..grid.dims.0 {
+            for (j, &c) in grid[i].iter().enumerate() {
+                if let Some(x) = blizzards.get_mut(&Bliz::from_char(c)) {
                     x.insert((i as isize, j as isize));
                 }
             }
         }
         self.dims = (rows, cols);
-        let prering = compute_ring(input, self.dims);
+        let prering = compute_ring(blizzards, self.dims);
         self.input = prering
             .iter()
-            .map(|blizzard| convert_blizzard_to_set(blizzard))
+            .map(convert_blizzard_to_set)
             .collect::<Vec<_>>();
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let start = State::new(0, (0, 1), 0);
         let end = bfs(
             &self.input,
             start,
             self.dims,
             (self.dims.0 + 1, self.dims.1),
-        );
+        )
+        .ok_or_else(unreachable)?;
 
-        format!("{:?}", end.time)
+        Ok(Answer::from(end.time))
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Result<Answer> {
         let init = (0, 1);
         let finish = (self.dims.0 + 1, self.dims.1);
         let start = State::new(0, init, 0);
-        let end = bfs(&self.input, start, self.dims, finish);
-        let start = bfs(&self.input, end, self.dims, init);
-        let end = bfs(&self.input, start, self.dims, finish);
-        format!("{:?}", end.time)
+        let end = bfs(&self.input, start, self.dims, finish).ok_or_else(unreachable)?;
+        let start = bfs(&self.input, end, self.dims, init).ok_or_else(unreachable)?;
+        let end = bfs(&self.input, start, self.dims, finish).ok_or_else(unreachable)?;
+        Ok(Answer::from(end.time))
     }
 }
 
+fn unreachable() -> Error {
+    Error::solve(DAY, "the blizzards never let us through")
+}
+
 fn convert_blizzard_to_set(blizzard: &Blizzards) -> TakenPositions {
     let mut union: TakenPositions = HashSet::new();
     for dir in &[R, L, U, D] {
@@ -155,39 +176,29 @@ impl State {
     }
 }
 
-fn bfs(ring: &Ring, start: State, dims: (isize, isize), goal: Position) -> State {
-    let mut queue = VecDeque::new();
-    let mut seen = HashSet::new();
-
-    queue.push_back(start);
-    seen.insert(start.map);
-    // While there are unexplored states...
-    while let Some(state) = queue.pop_front() {
-        // If the goal is reached, return
-        if state.map.me == goal {
-            return state;
-        } else {
-            for new_state in next_states(ring, &state, dims) {
-                if !seen.contains(&new_state.map) {
-                    queue.push_back(new_state);
-                    seen.insert(new_state.map);
-                }
-            }
-        }
-    }
-    panic!("Failed...")
+fn bfs(ring: &Ring, start: State, dims: (isize, isize), goal: Position) -> Option<State> {
+    // The time is left out of the searched nodes, so that waiting for a
+    // whole cycle of the blizzards leads back to a seen node.
+    let search = search::bfs(
+        [start.map],
+        |map| next_maps(ring, map, dims),
+        |map| map.me == goal,
+    );
+    let map = *search.goal()?;
+    let time = start.time + search.goal_distance()? as usize;
+    Some(State { map, time })
 }
 
-fn next_states(ring: &Ring, state: &State, dims: (isize, isize)) -> Vec<State> {
-    let mut new_states = Vec::new();
-    let new_idx = (state.map.idx + 1) % ring.len();
+fn next_maps(ring: &Ring, map: &Map, dims: (isize, isize)) -> Vec<Map> {
+    let mut new_maps = Vec::new();
+    let idx = (map.idx + 1) % ring.len();
     for (x, y) in [(-1, 0), (0, -1), (0, 0), (1, 0), (0, 1)] {
-        let new_me = (state.map.me.0 + x, state.map.me.1 + y);
-        if in_bounds(dims, new_me) && !ring[new_idx].contains(&new_me) {
-            new_states.push(State::new(new_idx, new_me, state.time + 1));
+        let me = (map.me.0 + x, map.me.1 + y);
+        if in_bounds(dims, me) && !ring[idx].contains(&me) {
+            new_maps.push(Map { idx, me });
         }
     }
-    new_states
+    new_maps
 }
 
 fn in_bounds((m, n): (isize, isize), (i, j): Position) -> bool {
@@ -1,10 +1,19 @@
-use aoc_2022_rust::Puzzle;
+use crate::{Answer, Error, Puzzle, Result};
+use nom::error::ErrorKind;
+
+const DAY: usize = 25;
 
 #[derive(Debug, Clone)]
 pub struct Day25 {
     input: Vec<Vec<i64>>,
 }
 
+impl Default for Day25 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day25 {
     pub fn new() -> Day25 {
         Day25 { input: Vec::new() }
@@ -15,14 +24,14 @@ impl Day25 {
     }
 }
 
-fn char_to_digit(c: char) -> i64 {
+fn char_to_digit(c: char) -> Option<i64> {
     match c {
-        '0' => 0,
-        '1' => 1,
-        '2' => 2,
-        '-' => -1,
-        '=' => -2,
-        _ => panic!("Unknown sysmbol!"),
+        '0' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        '-' => Some(-1),
+        '=' => Some(-2),
+        _ => None,
     }
 }
 
@@ -37,16 +46,22 @@ fn digit_to_char(d: i64) -> char {
     }
 }
 
+solvers!(Day25, 25);
+
 impl Puzzle for Day25 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/25.input");
-        for line in INPUT.lines() {
-            self.input
-                .push(line.chars().map(|c| char_to_digit(c)).collect::<Vec<_>>());
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            let snafu = line
+                .chars()
+                .map(char_to_digit)
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| Error::parse(DAY, i + 1, line, ErrorKind::OneOf))?;
+            self.input.push(snafu);
         }
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let suma: i64 = self.input.iter().map(|snafu| snafu_to_int(snafu)).sum();
 
         let code = int_to_snafu(suma)
@@ -54,11 +69,11 @@ impl Puzzle for Day25 {
             .map(|d| digit_to_char(*d))
             .collect::<String>();
 
-        format!("{:?}", code)
+        Ok(Answer::from(code))
     }
 
-    fn part2(&self) -> String {
-        format!("{:?}", "Not needed!")
+    fn part2(&self) -> Result<Answer> {
+        Ok(Answer::NotApplicable)
     }
 }
 
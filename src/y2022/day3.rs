@@ -0,0 +1,180 @@
+use crate::{Answer, Error, Puzzle, Result};
+use nom::error::ErrorKind;
+
+const DAY: usize = 3;
+
+/// Elves walk in groups of three in the puzzle.
+const GROUP_SIZE: usize = 3;
+
+/// A set of item types, one bit per priority: `a`..`z` are 1 to 26 and
+/// `A`..`Z` are 27 to 52.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// The set of every item type.
+    pub const ALL: ItemSet = ItemSet(((1 << 52) - 1) << 1);
+
+    /// The priority of item `c`, `None` if it is not an ASCII letter.
+    pub fn priority(c: char) -> Option<u32> {
+        match c {
+            'a'..='z' => Some(c as u32 - 'a' as u32 + 1),
+            'A'..='Z' => Some(c as u32 - 'A' as u32 + 27),
+            _ => None,
+        }
+    }
+
+    /// The item types in `items`, `None` if any is not an ASCII letter.
+    pub fn from_items(items: &str) -> Option<ItemSet> {
+        items
+            .chars()
+            .try_fold(0, |set, c| Some(set | 1 << ItemSet::priority(c)?))
+            .map(ItemSet)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    /// The item types found in every one of `sets`.
+    pub fn common(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+        sets.into_iter().fold(ItemSet::ALL, ItemSet::intersection)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The priorities of the item types, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |p| self.0 & 1 << p != 0)
+    }
+
+    /// The priority of the only item type, `None` unless there is exactly one.
+    pub fn single(self) -> Option<u32> {
+        (self.len() == 1).then(|| self.0.trailing_zeros())
+    }
+}
+
+/// The two compartments of a rucksack.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Rucksack {
+    left: ItemSet,
+    right: ItemSet,
+}
+
+impl Rucksack {
+    fn contents(&self) -> ItemSet {
+        self.left.union(self.right)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Day3 {
+    input: Vec<Rucksack>,
+    group_size: usize,
+}
+
+impl Default for Day3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day3 {
+    pub fn new() -> Day3 {
+        Day3::with_group_size(GROUP_SIZE)
+    }
+
+    /// Looks for badges shared by groups of `group_size` elves in part 2.
+    pub fn with_group_size(group_size: usize) -> Day3 {
+        assert!(group_size > 0, "groups need at least one elf");
+        Day3 {
+            input: Vec::new(),
+            group_size,
+        }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = Vec::new()
+    }
+}
+
+solvers!(Day3, 3);
+
+impl Puzzle for Day3 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            // Checked first, so that the byte length below counts letters
+            if !line.bytes().all(|b| b.is_ascii_alphabetic()) {
+                return Err(Error::parse(DAY, i + 1, line, ErrorKind::Alpha));
+            }
+            if !line.len().is_multiple_of(2) {
+                return Err(Error::parse(DAY, i + 1, line, ErrorKind::LengthValue));
+            }
+            let (left, right) = line.split_at(line.len() / 2);
+            let compartment = |items| {
+                ItemSet::from_items(items)
+                    .ok_or_else(|| Error::parse(DAY, i + 1, line, ErrorKind::Alpha))
+            };
+            self.input.push(Rucksack {
+                left: compartment(left)?,
+                right: compartment(right)?,
+            });
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut count = 0;
+        for (i, rucksack) in self.input.iter().enumerate() {
+            count += rucksack
+                .left
+                .intersection(rucksack.right)
+                .single()
+                .ok_or_else(|| {
+                    Error::solve(
+                        DAY,
+                        format!(
+                            "rucksack {:} does not have exactly one item type in both compartments",
+                            i + 1
+                        ),
+                    )
+                })?;
+        }
+        Ok(Answer::from(count))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        if !self.input.len().is_multiple_of(self.group_size) {
+            return Err(Error::solve(
+                DAY,
+                format!(
+                    "{:} rucksacks do not split into groups of {:}",
+                    self.input.len(),
+                    self.group_size
+                ),
+            ));
+        }
+        let mut count = 0;
+        for (g, group) in self.input.chunks(self.group_size).enumerate() {
+            count += ItemSet::common(group.iter().map(Rucksack::contents))
+                .single()
+                .ok_or_else(|| {
+                    Error::solve(
+                        DAY,
+                        format!("group {:} does not share exactly one item type", g + 1),
+                    )
+                })?;
+        }
+        Ok(Answer::from(count))
+    }
+}
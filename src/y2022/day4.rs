@@ -0,0 +1,64 @@
+use crate::parse::{parse_lines, unsigned};
+use crate::{Answer, Interval, Puzzle, Result};
+use nom::{character::complete::char, combinator::map_opt, sequence::separated_pair, IResult};
+
+const DAY: usize = 4;
+
+#[derive(Debug, Clone)]
+pub struct Day4 {
+    input: Vec<(Interval, Interval)>,
+}
+
+// A section range that ends before it starts is rejected
+fn parse_interval(input: &str) -> IResult<&str, Interval> {
+    map_opt(separated_pair(unsigned, char('-'), unsigned), |(a, b)| {
+        Interval::new(a, b)
+    })(input)
+}
+
+fn parse_pair(input: &str) -> IResult<&str, (Interval, Interval)> {
+    separated_pair(parse_interval, char(','), parse_interval)(input)
+}
+
+impl Default for Day4 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day4 {
+    pub fn new() -> Day4 {
+        Day4 { input: Vec::new() }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = Vec::new()
+    }
+}
+
+solvers!(Day4, 4);
+
+impl Puzzle for Day4 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        self.input = parse_lines(DAY, input, parse_pair)?;
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let count = self
+            .input
+            .iter()
+            .filter(|(int1, int2)| int1.covers(int2) || int2.covers(int1))
+            .count();
+        Ok(Answer::from(count))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let count = self
+            .input
+            .iter()
+            .filter(|(int1, int2)| int1.overlaps(int2))
+            .count();
+        Ok(Answer::from(count))
+    }
+}
@@ -0,0 +1,185 @@
+use crate::parse::{blocks, unsigned, Block};
+use crate::{parse_complete, Answer, Error, Puzzle, Result};
+use nom::{bytes::complete::tag, combinator::map, error::ErrorKind, sequence::tuple, IResult};
+
+const DAY: usize = 5;
+
+#[derive(Debug, Copy, Clone)]
+struct Instruction {
+    amount: usize,
+    from: usize,
+    to: usize,
+    line: usize,
+}
+
+/// The stacks of crates, each listed from bottom to top.
+#[derive(Debug, Clone)]
+struct State(Vec<Vec<char>>);
+
+#[derive(Debug, Clone)]
+pub struct Day5 {
+    state: State,
+    instructions: Vec<Instruction>,
+}
+
+/// How a crane sets down the crates it lifted off a stack.
+pub trait Crane {
+    /// Reorders `load`, the crates lifted in bottom to top order, into the
+    /// order they end up in on the target stack.
+    fn arrange(&self, load: &mut [char]);
+}
+
+/// Moves one crate at a time, so the lifted crates land reversed.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn arrange(&self, load: &mut [char]) {
+        load.reverse()
+    }
+}
+
+/// Moves all lifted crates at once, keeping their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn arrange(&self, _load: &mut [char]) {}
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, (usize, usize, usize)> {
+    map(
+        tuple((
+            tag("move "),
+            unsigned,
+            tag(" from "),
+            unsigned,
+            tag(" to "),
+            unsigned,
+        )),
+        |(_, amount, _, from, _, to)| (amount, from, to),
+    )(input)
+}
+
+/// Reads the drawing of the stacks: rows of `[X]` crates, four columns per
+/// stack, above a footer numbering the stacks from 1.
+fn parse_drawing(block: &Block) -> Result<State> {
+    let lines = block.numbered().collect::<Vec<_>>();
+    let (&(n, footer), rows) = lines.split_last().unwrap();
+    let numbers = footer.split_whitespace().collect::<Vec<_>>();
+    let numbered = (1..=numbers.len()).all(|k| numbers[k - 1] == k.to_string());
+    if !numbered {
+        return Err(Error::parse(DAY, n, footer, ErrorKind::Digit));
+    }
+
+    let mut stacks = vec![Vec::new(); numbers.len()];
+    // Rows are drawn top down, so read them bottom up. Once a row has no
+    // crate on a stack, no row above may have one.
+    let mut topped = vec![false; numbers.len()];
+    for &(n, row) in rows.iter().rev() {
+        let error = || Error::parse(DAY, n, row, ErrorKind::Verify);
+        let cells = row.as_bytes().chunks(4).collect::<Vec<_>>();
+        if cells.len() > stacks.len() {
+            return Err(error());
+        }
+        // Trailing blanks of a row may have been trimmed away
+        let cells = cells.into_iter().chain(std::iter::repeat(&b""[..]));
+        for ((stack, done), cell) in stacks.iter_mut().zip(topped.iter_mut()).zip(cells) {
+            let blank = |bytes: &[u8]| bytes.iter().all(|&b| b == b' ');
+            match cell {
+                [b'[', c, b']', rest @ ..] if c.is_ascii_alphabetic() && blank(rest) => {
+                    if *done {
+                        return Err(error());
+                    }
+                    stack.push(*c as char)
+                }
+                cell if blank(cell) => *done = true,
+                _ => return Err(error()),
+            }
+        }
+    }
+    Ok(State(stacks))
+}
+
+/// Runs all instructions with `crane` and reads the crates on top of the
+/// stacks. Empty stacks have nothing to show.
+fn operate(crane: &impl Crane, state: &State, instructions: &[Instruction]) -> Result<String> {
+    let mut stacks = state.0.clone();
+    for instr in instructions {
+        let from = &mut stacks[instr.from - 1];
+        let held = from.len();
+        if instr.amount > held {
+            return Err(Error::solve(
+                DAY,
+                format!(
+                    "line {:} moves {:} crates from stack {:}, which holds {:}",
+                    instr.line, instr.amount, instr.from, held
+                ),
+            ));
+        }
+        let mut load = from.split_off(held - instr.amount);
+        crane.arrange(&mut load);
+        stacks[instr.to - 1].extend(load);
+    }
+    Ok(stacks.iter().filter_map(|stack| stack.last()).collect())
+}
+
+impl Default for Day5 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day5 {
+    pub fn new() -> Day5 {
+        Day5 {
+            state: State(Vec::new()),
+            instructions: Vec::new(),
+        }
+    }
+
+    pub fn _clear(&mut self) {
+        self.state = State(Vec::new());
+        self.instructions = Vec::new()
+    }
+}
+
+solvers!(Day5, 5);
+
+impl Puzzle for Day5 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        let blocks = blocks(input);
+        let (drawing, moves) = match &blocks[..] {
+            [drawing, moves] => (drawing, moves),
+            _ => {
+                return Err(Error::solve(
+                    DAY,
+                    "expected the drawing and the moves, separated by a blank line",
+                ))
+            }
+        };
+        self.state = parse_drawing(drawing)?;
+        let stacks = self.state.0.len();
+        for (n, line) in moves.numbered() {
+            let (amount, from, to) = parse_complete(DAY, n, line, parse_instruction)?;
+            if !(1..=stacks).contains(&from) || !(1..=stacks).contains(&to) {
+                return Err(Error::parse(DAY, n, line, ErrorKind::Verify));
+            }
+            self.instructions.push(Instruction {
+                amount,
+                from,
+                to,
+                line: n,
+            });
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let tops = operate(&CrateMover9000, &self.state, &self.instructions)?;
+        Ok(Answer::from(tops))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let tops = operate(&CrateMover9001, &self.state, &self.instructions)?;
+        Ok(Answer::from(tops))
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{Answer, Error, Puzzle, Result};
+use std::io::{self, BufReader, Read};
+
+const DAY: usize = 6;
+
+/// Window sizes of the start-of-packet and start-of-message markers.
+const PACKET: usize = 4;
+const MESSAGE: usize = 14;
+
+#[derive(Debug, Clone)]
+pub struct Day6 {
+    input: Vec<u8>,
+}
+
+impl Default for Day6 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day6 {
+    pub fn new() -> Day6 {
+        Day6 { input: Vec::new() }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = Vec::new()
+    }
+}
+
+/// Finds markers in a stream of bytes fed one at a time: places where the
+/// last `size` bytes are all different. Each byte costs O(1), whatever the
+/// window size.
+#[derive(Debug, Clone)]
+pub struct MarkerDetector {
+    size: usize,
+    // The last `size` bytes, as a ring indexed by position
+    window: Vec<u8>,
+    counts: [usize; 256],
+    // Bytes in the window that repeat an earlier byte of the window
+    duplicates: usize,
+    position: usize,
+}
+
+impl MarkerDetector {
+    pub fn new(size: usize) -> MarkerDetector {
+        assert!(size > 0, "a marker needs at least one byte");
+        MarkerDetector {
+            size,
+            window: vec![0; size],
+            counts: [0; 256],
+            duplicates: 0,
+            position: 0,
+        }
+    }
+
+    /// Feeds the next byte. Returns the number of bytes read so far if they
+    /// end with a marker.
+    pub fn push(&mut self, byte: u8) -> Option<usize> {
+        let slot = self.position % self.size;
+        if self.position >= self.size {
+            let old = self.window[slot] as usize;
+            if self.counts[old] > 1 {
+                self.duplicates -= 1;
+            }
+            self.counts[old] -= 1;
+        }
+        if self.counts[byte as usize] > 0 {
+            self.duplicates += 1;
+        }
+        self.counts[byte as usize] += 1;
+        self.window[slot] = byte;
+        self.position += 1;
+        (self.position >= self.size && self.duplicates == 0).then_some(self.position)
+    }
+}
+
+/// Every marker of `size` bytes in `signal`, as the number of bytes read
+/// when it is complete.
+pub fn markers(signal: &[u8], size: usize) -> impl Iterator<Item = usize> + '_ {
+    let mut detector = MarkerDetector::new(size);
+    signal.iter().filter_map(move |&byte| detector.push(byte))
+}
+
+pub fn first_marker(signal: &[u8], size: usize) -> Option<usize> {
+    markers(signal, size).next()
+}
+
+/// Like `markers`, but reads the signal from `reader` as it goes, so that
+/// it never has to fit in memory.
+pub fn stream_markers(reader: impl Read, size: usize) -> impl Iterator<Item = io::Result<usize>> {
+    let mut detector = MarkerDetector::new(size);
+    BufReader::new(reader)
+        .bytes()
+        .filter_map(move |byte| match byte {
+            Ok(byte) => detector.push(byte).map(Ok),
+            Err(e) => Some(Err(e)),
+        })
+}
+
+solvers!(Day6, 6);
+
+impl Puzzle for Day6 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        self.input = input.trim_end().as_bytes().to_vec();
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let marker = first_marker(&self.input, PACKET)
+            .ok_or_else(|| Error::solve(DAY, "the signal has no start-of-packet marker"))?;
+        Ok(Answer::from(marker))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let marker = first_marker(&self.input, MESSAGE)
+            .ok_or_else(|| Error::solve(DAY, "the signal has no start-of-message marker"))?;
+        Ok(Answer::from(marker))
+    }
+}
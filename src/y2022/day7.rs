@@ -1,6 +1,9 @@
-use aoc_2022_rust::Puzzle;
+use crate::{Answer, Error, Puzzle, Result};
+use nom::error::ErrorKind;
 use std::collections::HashMap;
 
+const DAY: usize = 7;
+
 #[derive(Debug, Clone)]
 struct Dir {
     name: String,
@@ -32,6 +35,12 @@ pub struct Day7 {
     input: HashMap<String, Contents>,
 }
 
+impl Default for Day7 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day7 {
     pub fn new() -> Day7 {
         Day7 {
@@ -44,73 +53,83 @@ impl Day7 {
     }
 }
 
-fn parse_file(line: &str) -> File {
+fn parse_file(line: &str) -> Option<File> {
     let size = line
         .chars()
-        .take_while(|c| c.is_digit(10))
+        .take_while(|c| c.is_ascii_digit())
         .collect::<String>()
         .parse::<usize>()
-        .unwrap();
+        .ok()?;
     let name = line
         .chars()
-        .skip_while(|c| c.is_digit(10) || c.is_whitespace())
+        .skip_while(|c| c.is_ascii_digit() || c.is_whitespace())
         .collect::<String>();
-    File { _name: name, size }
+    Some(File { _name: name, size })
 }
 
+solvers!(Day7, 7);
+
 impl Puzzle for Day7 {
-    fn load_input(&mut self) {
-        const INPUT: &str = include_str!("../inputs/7.input");
+    fn load_input(&mut self, input: &str) -> Result<()> {
         let mut tree = HashMap::from([("".to_string(), Contents::new())]);
         let mut current = String::new();
-        for line in INPUT.lines() {
+        for (i, line) in input.lines().enumerate() {
             if line.starts_with("$ cd /") {
                 current = "".to_string();
             } else if line.starts_with("$ cd ..") {
-                current = current.rsplit_once('/').unwrap().0.to_string();
+                current = current
+                    .rsplit_once('/')
+                    .ok_or_else(|| Error::parse(DAY, i + 1, line, ErrorKind::Verify))?
+                    .0
+                    .to_string();
             } else if line.starts_with("$ cd ") {
                 let name = format!("/{:}", line.chars().skip(5).collect::<String>());
                 current.push_str(&name);
             } else if line.starts_with("dir") {
                 let name = format!("{:}/{:}", current, line.chars().skip(4).collect::<String>());
-                if tree.get(&name).is_none() {
+                if !tree.contains_key(&name) {
                     tree.insert(name.clone(), Contents::new());
                     if let Some(t) = tree.get_mut(&current) {
                         t.dirs.push(Dir { name });
                     }
                 }
-            } else if line.chars().nth(0).unwrap().is_digit(10) {
-                let file = parse_file(&line);
+            } else if line.starts_with(|c: char| c.is_ascii_digit()) {
+                let file = parse_file(line)
+                    .ok_or_else(|| Error::parse(DAY, i + 1, line, ErrorKind::Digit))?;
                 if let Some(t) = tree.get_mut(&current) {
                     t.files.push(file);
                 }
             }
         }
         self.input = tree;
+        Ok(())
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Result<Answer> {
         let mut dir_sizes = HashMap::<String, usize>::new();
         traverse("", &self.input, &mut dir_sizes);
-        format!(
-            "{:}",
-            dir_sizes.values().filter(|&v| *v <= 100000).sum::<usize>()
-        )
+        Ok(Answer::from(
+            dir_sizes.values().filter(|&v| *v <= 100000).sum::<usize>(),
+        ))
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Result<Answer> {
         let size_of_device = 70000000usize;
         let mut dir_sizes = HashMap::<String, usize>::new();
         traverse("", &self.input, &mut dir_sizes);
-        let available = size_of_device - dir_sizes[""];
-        let needed = 30000000usize - available;
+        let available = size_of_device
+            .checked_sub(dir_sizes[""])
+            .ok_or_else(|| Error::solve(DAY, "files do not fit on the device"))?;
+        let needed = 30000000usize
+            .checked_sub(available)
+            .ok_or_else(|| Error::solve(DAY, "there is already enough free space"))?;
         let mut pick = dir_sizes[""];
         for (_, size) in dir_sizes {
             if size >= needed && size < pick {
                 pick = size
             }
         }
-        format!("{:}", pick)
+        Ok(Answer::from(pick))
     }
 }
 
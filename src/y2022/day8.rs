@@ -0,0 +1,81 @@
+use crate::grid::{Pos, DIRECTIONS4};
+use crate::{Answer, Grid, Puzzle, Result};
+
+use std::fmt::Debug;
+
+const DAY: usize = 8;
+
+#[derive(Debug, Clone)]
+pub struct Day8 {
+    input: Grid<isize>,
+}
+
+impl Default for Day8 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day8 {
+    pub fn new() -> Day8 {
+        Day8 { input: Grid::new() }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = Grid::new()
+    }
+}
+
+solvers!(Day8, 8);
+
+impl Puzzle for Day8 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        self.input = Grid::parse(input, |c| c.to_digit(10).map(|d| d as isize))
+            .map_err(|e| e.at_day(DAY, input))?;
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(Answer::from(visible(&self.input)))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(Answer::from(score(&self.input)))
+    }
+}
+
+fn visible(grid: &Grid<isize>) -> usize {
+    grid.enumerate()
+        .filter(|&(pos, &tree)| {
+            DIRECTIONS4
+                .iter()
+                .any(|&dir| grid.ray(pos, dir).all(|(_, &other)| other < tree))
+        })
+        .count()
+}
+
+/// Number of trees seen from `pos` looking in direction `dir`: all up to and
+/// including the first one that is at least as tall.
+fn viewing_distance(grid: &Grid<isize>, pos: Pos, dir: (isize, isize)) -> usize {
+    let tree = grid[pos];
+    let mut seen = 0;
+    for (_, &other) in grid.ray(pos, dir) {
+        seen += 1;
+        if other >= tree {
+            break;
+        }
+    }
+    seen
+}
+
+fn score(grid: &Grid<isize>) -> usize {
+    grid.positions()
+        .map(|pos| {
+            DIRECTIONS4
+                .iter()
+                .map(|&dir| viewing_distance(grid, pos, dir))
+                .product()
+        })
+        .max()
+        .unwrap_or(0)
+}
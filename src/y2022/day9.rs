@@ -0,0 +1,96 @@
+use crate::{Answer, Direction4, Error, Point2, Puzzle, Result, SparseGrid};
+use nom::error::ErrorKind;
+
+const DAY: usize = 9;
+
+#[derive(Debug, Clone)]
+pub struct Day9 {
+    input: Vec<Instruction>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Instruction {
+    dir: Direction4,
+    steps: isize,
+}
+
+impl Default for Day9 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day9 {
+    pub fn new() -> Day9 {
+        Day9 { input: Vec::new() }
+    }
+
+    pub fn _clear(&mut self) {
+        self.input = Vec::new()
+    }
+}
+
+type Position = Point2<isize>;
+
+fn parse(line: &str) -> Option<Instruction> {
+    let (dir, steps) = line.split_once(' ')?;
+    let steps = steps.parse::<isize>().ok()?;
+    let dir = match dir {
+        "L" => Direction4::Left,
+        "R" => Direction4::Right,
+        "U" => Direction4::Up,
+        "D" => Direction4::Down,
+        _ => return None,
+    };
+    Some(Instruction { dir, steps })
+}
+
+fn follow(head: Position, tail: Position) -> Position {
+    if head.chebyshev(&tail) > 1 {
+        tail + (head - tail).signum()
+    } else {
+        tail
+    }
+}
+
+fn simulate(instructions: &Vec<Instruction>, n: usize) -> usize {
+    let mut visited = SparseGrid::from_iter([((0, 0), ())]);
+    let mut knots = Vec::with_capacity(n);
+    let mut newknots = Vec::with_capacity(n);
+    for _ in 0..n {
+        knots.push(Position::default());
+        newknots.push(Position::default());
+    }
+    for instr in instructions {
+        for _ in 0..instr.steps {
+            newknots[0] = knots[0] + instr.dir.unit();
+            for i in 1..n {
+                newknots[i] = follow(newknots[i - 1], knots[i]);
+            }
+            visited.insert(newknots[n - 1].to_row_col(), ());
+            std::mem::swap(&mut knots, &mut newknots);
+        }
+    }
+    visited.len()
+}
+
+solvers!(Day9, 9);
+
+impl Puzzle for Day9 {
+    fn load_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            let instruction =
+                parse(line).ok_or_else(|| Error::parse(DAY, i + 1, line, ErrorKind::OneOf))?;
+            self.input.push(instruction);
+        }
+        Ok(())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(Answer::from(simulate(&self.input, 2)))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(Answer::from(simulate(&self.input, 10)))
+    }
+}